@@ -0,0 +1,73 @@
+//! Detects the version of `libproj` found by `proj-sys`, so wrappers of APIs added in later PROJ
+//! versions are only compiled when they're available, and embeds the grids requested by the
+//! `bundled_grids` feature
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// The (major, minor) versions which added APIs this crate wraps. Each is exposed as a cfg flag,
+// e.g. `proj_8_2`, if the linked libproj is at least that version.
+const VERSIONS: &[(u32, u32)] = &[(8, 2), (9, 2), (9, 4)];
+
+fn main() {
+    if env::var_os("CARGO_FEATURE_BUNDLED_GRIDS").is_some() {
+        bundle_grids();
+    }
+    detect_version();
+}
+
+/// Generate the list of grids to embed from the `PROJ_BUNDLED_GRIDS` environment variable, which
+/// holds paths to grid files separated like `PATH`
+fn bundle_grids() {
+    println!("cargo:rerun-if-env-changed=PROJ_BUNDLED_GRIDS");
+    let mut entries = String::new();
+    match env::var_os("PROJ_BUNDLED_GRIDS") {
+        Some(paths) => {
+            for path in env::split_paths(&paths).filter(|path| !path.as_os_str().is_empty()) {
+                let path = fs::canonicalize(&path).unwrap_or_else(|e| {
+                    panic!("Couldn't find the bundled grid {}: {}", path.display(), e)
+                });
+                let name = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_else(|| panic!("{} isn't a valid grid name", path.display()));
+                println!("cargo:rerun-if-changed={}", path.display());
+                entries.push_str(&format!(
+                    "    ({:?}, include_bytes!({:?})),\n",
+                    name,
+                    path.display().to_string()
+                ));
+            }
+        }
+        None => println!(
+            "cargo:warning=the bundled_grids feature is enabled but PROJ_BUNDLED_GRIDS isn't set, so no grids will be bundled"
+        ),
+    }
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR isn't set"));
+    fs::write(
+        out.join("bundled_grids.rs"),
+        format!(
+            "pub(crate) static BUNDLED_GRIDS: &[(&str, &[u8])] = &[\n{}];\n",
+            entries
+        ),
+    )
+    .expect("Couldn't write the list of bundled grids");
+}
+
+fn detect_version() {
+    for (major, minor) in VERSIONS {
+        println!("cargo:rustc-check-cfg=cfg(proj_{}_{})", major, minor);
+    }
+    // Set by proj-sys's build script; absent when it doesn't build or link libproj (e.g. on docs.rs)
+    let version = match env::var("DEP_PROJ_VERSION") {
+        Ok(version) => version,
+        Err(_) => return,
+    };
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+    let linked: (u32, u32) = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    for &(major, minor) in VERSIONS {
+        if linked >= (major, minor) {
+            println!("cargo:rustc-cfg=proj_{}_{}", major, minor);
+        }
+    }
+}
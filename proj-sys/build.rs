@@ -1,20 +1,30 @@
 use bindgen;
+#[cfg(not(feature = "nobuild"))]
 use cmake;
+#[cfg(not(feature = "nobuild"))]
 use flate2::read::GzDecoder;
+#[cfg(not(feature = "nobuild"))]
 use std::fs::File;
 
 use pkg_config;
+#[cfg(not(feature = "nobuild"))]
 use std::env;
+#[cfg(not(feature = "nobuild"))]
 use std::path::PathBuf;
+#[cfg(not(feature = "nobuild"))]
 use tar::Archive;
 
 const MINIMUM_PROJ_VERSION: &str = "7.1.0";
+#[cfg(not(feature = "nobuild"))]
+const BUNDLED_PROJ_VERSION: &str = "7.1.0";
 
 #[cfg(feature = "nobuild")]
 fn main() {} // Skip the build script on docs.rs
 
 #[cfg(not(feature = "nobuild"))]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut source_build = true;
+    let mut version = BUNDLED_PROJ_VERSION.to_string();
     let include_path = if cfg!(feature = "bundled_proj") {
         eprintln!("feature flags specified source build");
         build_from_source()?
@@ -35,6 +45,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("cargo:rustc-link-search=native={:?}", pk.link_paths[0]);
             println!("cargo:rustc-link-lib=proj");
 
+            source_build = false;
+            version = pk.version.clone();
             Ok(pk.include_paths[0].clone())
         })
         .or_else(|err| {
@@ -62,10 +74,70 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings.write_to_file(out_path.join("bindings.rs"))?;
 
+    // Exposed to dependents' build scripts as DEP_PROJ_VERSION
+    println!("cargo:version={}", version);
+
+    // Exposed as PROJ_HAS_CURL, PROJ_HAS_TIFF and PROJ_HAS_MUTEX. The source build disables curl;
+    // an installed libproj's dependencies are listed in its pkg-config file. Mutex support can't
+    // be detected, but is on unless PROJ was configured with USE_THREAD=OFF.
+    let (curl, tiff) = if source_build {
+        (false, true)
+    } else {
+        system_dependencies()
+    };
+    for (name, enabled) in &[("CURL", curl), ("TIFF", tiff), ("MUTEX", true)] {
+        let value = if *enabled { "1" } else { "" };
+        println!("cargo:rustc-env=PROJ_SYS_{}={}", name, value);
+    }
+
+    if cfg!(feature = "bundled_db") {
+        embed_database(source_build)?;
+    }
+
+    Ok(())
+}
+
+// whether an installed libproj links libcurl and libtiff, from the private libraries in its
+// pkg-config file
+#[cfg(not(feature = "nobuild"))]
+fn system_dependencies() -> (bool, bool) {
+    let library = pkg_config::Config::new()
+        .statik(true)
+        .cargo_metadata(false)
+        .probe("proj");
+    match library {
+        Ok(library) => (
+            library.libs.iter().any(|lib| lib == "curl"),
+            library.libs.iter().any(|lib| lib.starts_with("tiff")),
+        ),
+        Err(_) => (false, false),
+    }
+}
+
+// tells rustc where to find the proj.db embedded by the `bundled_db` feature
+#[cfg(not(feature = "nobuild"))]
+fn embed_database(source_build: bool) -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-env-changed=PROJ_DB_PATH");
+    let path = match env::var_os("PROJ_DB_PATH") {
+        Some(path) => PathBuf::from(path),
+        // the source build installs its data files in $OUT_DIR/share/proj
+        None if source_build => PathBuf::from(env::var("OUT_DIR")?).join("share/proj/proj.db"),
+        None => PathBuf::from(pkg_config::get_variable("proj", "datadir")?).join("proj.db"),
+    };
+    if !path.is_file() {
+        return Err(format!(
+            "proj.db wasn't found at {}; set PROJ_DB_PATH to its location",
+            path.display()
+        )
+        .into());
+    }
+    println!("cargo:rerun-if-changed={}", path.display());
+    println!("cargo:rustc-env=PROJ_SYS_DB={}", path.display());
     Ok(())
 }
 
 // returns the path of "inlude" for the built proj
+#[cfg(not(feature = "nobuild"))]
 fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
     eprintln!("building libproj from source");
     if let Ok(val) = &env::var("_PROJ_SYS_TEST_EXPECT_BUILD_FROM_SRC") {
@@ -95,6 +167,18 @@ fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>>
     config.define("BUILD_PROJSYNC", "OFF");
     config.define("ENABLE_CURL", "OFF");
     config.define("ENABLE_TIFF", "ON");
+    configure_target(&mut config);
+    let static_tiff = if cfg!(feature = "bundled_proj_tiff") {
+        Some(static_library(&mut config, "libtiff-4", "tiff", "TIFF")?)
+    } else {
+        None
+    };
+    let bundled_sqlite = use_bundled_sqlite(&mut config);
+    let static_sqlite = if cfg!(feature = "bundled_proj_static") && !bundled_sqlite {
+        Some(static_library(&mut config, "sqlite3", "sqlite3", "SQLITE3")?)
+    } else {
+        None
+    };
     let proj = config.build();
     // Tell cargo to tell rustc to link libproj, and where to find it
     // libproj will be built in $OUT_DIR/lib
@@ -111,16 +195,118 @@ fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>>
         "cargo:rustc-link-search={}",
         &out_path.join("build/lib").display()
     );
-    // The PROJ library needs SQLite and the C++ standard library.
-    println!("cargo:rustc-link-lib=dylib=sqlite3");
-    println!("cargo:rustc-link-lib=dylib=tiff");
-    if cfg!(target_os = "linux") {
-        println!("cargo:rustc-link-lib=dylib=stdc++");
-    } else if cfg!(target_os = "macos") {
-        println!("cargo:rustc-link-lib=dylib=c++");
-    } else {
-        println!("cargo:warning=proj-sys: Not configuring an explicit C++ standard library on this target.");
+    // The PROJ library needs SQLite, libtiff, and the C++ standard library.
+    // (libsqlite3-sys links the SQLite it builds itself)
+    if !bundled_sqlite {
+        link_library("sqlite3", static_sqlite);
+    }
+    link_library("tiff", static_tiff);
+    let target_os = env::var("CARGO_CFG_TARGET_OS")?;
+    let target_env = env::var("CARGO_CFG_TARGET_ENV")?;
+    match (target_os.as_str(), target_env.as_str()) {
+        // musl binaries are usually fully static, so the C++ runtime is linked statically too
+        ("linux", "musl") => println!("cargo:rustc-link-lib=static=stdc++"),
+        ("linux", _) => println!("cargo:rustc-link-lib=dylib=stdc++"),
+        ("macos", _) | ("ios", _) => println!("cargo:rustc-link-lib=dylib=c++"),
+        ("android", _) => println!("cargo:rustc-link-lib=dylib=c++_shared"),
+        // MSVC links its C++ runtime by itself, but PROJ locates its user directory with the
+        // shell API
+        ("windows", "msvc") => {
+            println!("cargo:rustc-link-lib=dylib=shell32");
+            println!("cargo:rustc-link-lib=dylib=ole32");
+        }
+        _ => println!("cargo:warning=proj-sys: Not configuring an explicit C++ standard library on this target."),
     }
 
     Ok(proj.join("include"))
 }
+
+// configures the cmake build for targets it can't set up by itself
+#[cfg(not(feature = "nobuild"))]
+fn configure_target(config: &mut cmake::Config) {
+    println!("cargo:rerun-if-env-changed=ANDROID_NDK_HOME");
+    println!("cargo:rerun-if-env-changed=ANDROID_PLATFORM");
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    // Android builds need the NDK's toolchain file, unless CMAKE_TOOLCHAIN_FILE is already set
+    if os == "android" && env::var_os("CMAKE_TOOLCHAIN_FILE").is_none() {
+        if let Some(ndk) = env::var_os("ANDROID_NDK_HOME") {
+            let abi = match arch.as_str() {
+                "aarch64" => "arm64-v8a",
+                "arm" => "armeabi-v7a",
+                "x86" => "x86",
+                _ => "x86_64",
+            };
+            config.define(
+                "CMAKE_TOOLCHAIN_FILE",
+                PathBuf::from(ndk).join("build/cmake/android.toolchain.cmake"),
+            );
+            config.define("ANDROID_ABI", abi);
+            config.define(
+                "ANDROID_PLATFORM",
+                env::var("ANDROID_PLATFORM").unwrap_or_else(|_| "android-21".to_string()),
+            );
+        }
+    }
+}
+
+// SQLite is rarely installed for MSVC outside of vcpkg, so MSVC builds depend on libsqlite3-sys,
+// which builds it from source. If it has, points PROJ's build at its header and library
+#[cfg(not(feature = "nobuild"))]
+fn use_bundled_sqlite(config: &mut cmake::Config) -> bool {
+    let (include, lib_dir) = match (
+        env::var_os("DEP_SQLITE3_INCLUDE"),
+        env::var_os("DEP_SQLITE3_LIB_DIR"),
+    ) {
+        (Some(include), Some(lib_dir)) => (PathBuf::from(include), PathBuf::from(lib_dir)),
+        _ => return false,
+    };
+    eprintln!("using the SQLite built by libsqlite3-sys");
+    config.define("SQLITE3_INCLUDE_DIR", &include);
+    config.define("SQLITE3_LIBRARY", lib_dir.join("sqlite3.lib"));
+    true
+}
+
+// locates a static library with pkg-config, and points PROJ's build at it
+#[cfg(not(feature = "nobuild"))]
+fn static_library(
+    config: &mut cmake::Config,
+    package: &str,
+    name: &str,
+    cmake_prefix: &str,
+) -> Result<pkg_config::Library, Box<dyn std::error::Error>> {
+    let library = pkg_config::Config::new()
+        .statik(true)
+        .cargo_metadata(false)
+        .probe(package)?;
+    let archive = library
+        .link_paths
+        .iter()
+        .map(|path| path.join(format!("lib{}.a", name)))
+        .find(|archive| archive.is_file())
+        .ok_or_else(|| format!("a static lib{} (lib{}.a) wasn't found", name, name))?;
+    if let Some(include) = library.include_paths.first() {
+        config.define(format!("{}_INCLUDE_DIR", cmake_prefix), include);
+    }
+    config.define(format!("{}_LIBRARY", cmake_prefix), &archive);
+    eprintln!("linking lib{} statically from {:?}", name, archive);
+    Ok(library)
+}
+
+// links a library found by static_library statically, or the system's shared library
+#[cfg(not(feature = "nobuild"))]
+fn link_library(name: &str, static_library: Option<pkg_config::Library>) {
+    match static_library {
+        Some(library) => {
+            for path in &library.link_paths {
+                println!("cargo:rustc-link-search=native={}", path.display());
+            }
+            println!("cargo:rustc-link-lib=static={}", name);
+            // the library's own dependencies (zlib, libjpeg, ...) are linked dynamically
+            for lib in library.libs.iter().filter(|lib| *lib != name) {
+                println!("cargo:rustc-link-lib=dylib={}", lib);
+            }
+        }
+        None => println!("cargo:rustc-link-lib=dylib={}", name),
+    }
+}
@@ -27,9 +27,75 @@
 //! `libproj` **without** its native network functionality; you will have to
 //! implement your own set of callbacks if you wish to make use of them (see the
 //! [`proj`](https://crates.io/crates/proj) crate for an example).
+//!
+//! `bundled_proj_tiff` - as `bundled_proj`, but links `libtiff` statically, so the
+//! PROJ-data GeoTIFF grids can be read without `libtiff` being installed where the
+//! application runs. A static `libtiff` (`libtiff.a`) must be findable using pkg-config.
+//!
+//! `bundled_proj_static` - as `bundled_proj_tiff`, but also links SQLite statically
+//! (a static `libsqlite3.a` must be findable using pkg-config), for fully static binaries.
+//!
+//! The bundled build supports cross-compilation, including to `x86_64-unknown-linux-musl`
+//! (where the C++ runtime is linked statically), `aarch64-unknown-linux-gnu`, Android and iOS.
+//! Android builds use the NDK in `ANDROID_NDK_HOME`, for the API level in `ANDROID_PLATFORM`
+//! (`android-21` by default). When cross-compiling, pkg-config must be configured for the target
+//! (e.g. using `PKG_CONFIG_SYSROOT_DIR`), and PROJ's build needs the `sqlite3` command-line tool
+//! on the host.
+//!
+//! On MSVC targets, the bundled build links the SQLite which
+//! [`libsqlite3-sys`](https://crates.io/crates/libsqlite3-sys) builds from source, so vcpkg isn't
+//! needed. PROJ's build still runs the `sqlite3` command-line tool to generate `proj.db`, so it
+//! must be on `PATH`.
+//!
+//! Whether the linked `libproj` was built with libcurl, libtiff and mutex support is exposed as
+//! [`PROJ_HAS_CURL`](constant.PROJ_HAS_CURL.html), [`PROJ_HAS_TIFF`](constant.PROJ_HAS_TIFF.html)
+//! and [`PROJ_HAS_MUTEX`](constant.PROJ_HAS_MUTEX.html).
+//!
+//! `bundled_db` - embeds PROJ's database, `proj.db`, as [`PROJ_DB`](static.PROJ_DB.html). It's
+//! taken from the path in the `PROJ_DB_PATH` environment variable if that's set at build time,
+//! and otherwise from the installation being linked against.
 
 #[cfg(not(feature = "nobuild"))]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+// Links the SQLite which the bundled libproj is built against on MSVC
+#[cfg(all(feature = "bundled_proj", target_env = "msvc"))]
+extern crate libsqlite3_sys;
+
+/// Whether the linked `libproj` was built with its own network support, using libcurl
+#[cfg(not(feature = "nobuild"))]
+pub const PROJ_HAS_CURL: bool = !env!("PROJ_SYS_CURL").is_empty();
+
+/// Whether the linked `libproj` was built with libtiff, which is needed to read GeoTIFF grids
+#[cfg(not(feature = "nobuild"))]
+pub const PROJ_HAS_TIFF: bool = !env!("PROJ_SYS_TIFF").is_empty();
+
+/// Whether the linked `libproj` was built with mutex support, making it safe to use separate
+/// contexts from different threads
+#[cfg(not(feature = "nobuild"))]
+pub const PROJ_HAS_MUTEX: bool = !env!("PROJ_SYS_MUTEX").is_empty();
+
 #[cfg(feature = "nobuild")]
 include!("bindings_docs-rs.rs");
+
+/// Whether the linked `libproj` was built with its own network support (false, as nothing is
+/// built on docs.rs)
+#[cfg(feature = "nobuild")]
+pub const PROJ_HAS_CURL: bool = false;
+
+/// Whether the linked `libproj` was built with libtiff (false, as nothing is built on docs.rs)
+#[cfg(feature = "nobuild")]
+pub const PROJ_HAS_TIFF: bool = false;
+
+/// Whether the linked `libproj` was built with mutex support (false, as nothing is built on
+/// docs.rs)
+#[cfg(feature = "nobuild")]
+pub const PROJ_HAS_MUTEX: bool = false;
+
+/// The contents of `proj.db`
+#[cfg(all(feature = "bundled_db", not(feature = "nobuild")))]
+pub static PROJ_DB: &[u8] = include_bytes!(env!("PROJ_SYS_DB"));
+
+/// The contents of `proj.db` (empty, as nothing is built on docs.rs)
+#[cfg(all(feature = "bundled_db", feature = "nobuild"))]
+pub static PROJ_DB: &[u8] = &[];
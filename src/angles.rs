@@ -0,0 +1,74 @@
+//! Converting angles between radians, degrees, and degrees, minutes and seconds, as PROJ's
+//! command-line tools do
+use crate::proj::ProjError;
+use libc::{c_char, c_int};
+use proj_sys::{proj_dmstor, proj_rtodms, proj_todeg, proj_torad};
+use std::ffi::{CStr, CString};
+
+/// Convert an angle in degrees to radians
+pub fn to_radians(degrees: f64) -> f64 {
+    unsafe { proj_torad(degrees) }
+}
+
+/// Convert an angle in radians to degrees
+pub fn to_degrees(radians: f64) -> f64 {
+    unsafe { proj_todeg(radians) }
+}
+
+/// Parse an angle in degrees, minutes and seconds, e.g. `45d30'30"N`, returning it in radians
+///
+/// Minutes and seconds are optional, and fractional parts are allowed on the last component.
+/// A `+` or `-` prefix, or an `N`, `E`, `S` or `W` suffix, gives the angle's sign. A value
+/// suffixed with `r` is already in radians.
+///
+/// ```rust
+/// use proj::{dms_to_radians, to_degrees};
+///
+/// let latitude = dms_to_radians("45d30'30\"N").unwrap();
+/// assert!((to_degrees(latitude) - 45.508_333).abs() < 1e-6);
+/// assert!(dms_to_radians("45 degrees").is_err());
+/// ```
+///
+/// # Safety
+/// This function contains unsafe code.
+pub fn dms_to_radians(dms: &str) -> Result<f64, ProjError> {
+    let invalid = || ProjError::InvalidDms(dms.to_string());
+    let trimmed = dms.trim();
+    let c_dms = CString::new(trimmed).map_err(|_| invalid())?;
+    let mut end: *mut c_char = std::ptr::null_mut();
+    let radians = unsafe { proj_dmstor(c_dms.as_ptr(), &mut end) };
+    // PROJ stops at the first character it doesn't understand; all of the input must be used
+    let consumed = end as usize - c_dms.as_ptr() as usize;
+    if trimmed.is_empty() || consumed != trimmed.len() || radians == f64::INFINITY {
+        return Err(invalid());
+    }
+    Ok(radians)
+}
+
+/// Format an angle in radians as degrees, minutes and seconds, e.g. `45d30'30"N`
+///
+/// `hemispheres` gives the ASCII suffixes for positive and negative angles, e.g.
+/// `Some((b'N', b'S'))` for latitudes; without them, negative angles are prefixed with `-`.
+/// Seconds are rounded to three decimal places, and zero components are omitted.
+///
+/// ```rust
+/// use proj::{radians_to_dms, to_radians};
+///
+/// let formatted = radians_to_dms(to_radians(-33.5), Some((b'N', b'S')));
+/// assert_eq!(formatted, "33d30'S");
+/// assert_eq!(radians_to_dms(to_radians(-33.5), None), "-33d30'");
+/// ```
+///
+/// # Safety
+/// This function contains unsafe code.
+pub fn radians_to_dms(radians: f64, hemispheres: Option<(u8, u8)>) -> String {
+    let (pos, neg) = hemispheres.unwrap_or((0, 0));
+    // Large enough for any angle PROJ formats: degrees, minutes, seconds and a suffix
+    let mut buffer = [0 as c_char; 64];
+    unsafe {
+        proj_rtodms(buffer.as_mut_ptr(), radians, pos as c_int, neg as c_int);
+        CStr::from_ptr(buffer.as_ptr())
+            .to_string_lossy()
+            .into_owned()
+    }
+}
@@ -0,0 +1,159 @@
+//! Guessing whether coordinates have had their axes swapped, before transforming them
+use crate::proj::{Area, Proj, ProjError};
+use geo_types::Point;
+use num_traits::Float;
+
+/// The order of the horizontal axes of coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AxisOrder {
+    /// Longitude, latitude or easting, northing: the order used by transformations whose
+    /// [axis order is normalised](struct.ProjBuilder.html#method.set_normalize_axis_order)
+    EastNorth,
+    /// Latitude, longitude or northing, easting
+    NorthEast,
+}
+
+/// The result of [`detect_axis_order`](fn.detect_axis_order.html)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AxisOrderGuess {
+    /// The order the sample points most likely have
+    pub order: AxisOrder,
+    /// The fraction of the sample points, between 0 and 1, which fit the CRS's area of use in
+    /// `order` but not in the other order. 0 means that the points don't tell the orders apart
+    pub confidence: f64,
+    /// The order of the CRS's own axes, as defined by its authority, e.g. `NorthEast` for
+    /// `EPSG:4326`
+    pub crs_order: AxisOrder,
+}
+
+/// The number of points sampled along each edge of an area of use
+const EDGE_SAMPLES: usize = 21;
+
+/// The fraction of its width and height by which a projected area of use's bounding box is
+/// enlarged, so that points just outside it still fit
+const MARGIN: f64 = 0.1;
+
+/// The bounding box of `area` in the normalised axis order and units of `crs`, as
+/// `[min_x, min_y, max_x, max_y]`
+fn crs_bounds(area: &Area, crs: &str, geographic: bool) -> Result<Option<[f64; 4]>, ProjError> {
+    let from_lon_lat = Proj::new_known_crs("EPSG:4326", crs, None)?;
+    let (west, south, north) = (area.west(), area.south(), area.north());
+    let east = if area.east() < west {
+        area.east() + 360.0
+    } else {
+        area.east()
+    };
+    let mut bounds: Option<[f64; 4]> = None;
+    for i in 0..EDGE_SAMPLES {
+        let fraction = i as f64 / (EDGE_SAMPLES - 1) as f64;
+        let lon = west + (east - west) * fraction;
+        let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+        let lat = south + (north - south) * fraction;
+        let edges = [(lon, south), (lon, north), (west, lat), (area.east(), lat)];
+        for edge in edges {
+            let point: Point<f64> = match from_lon_lat.convert(edge) {
+                Ok(point) if point.x().is_finite() && point.y().is_finite() => point,
+                _ => continue,
+            };
+            bounds = Some(match bounds {
+                Some([min_x, min_y, max_x, max_y]) => [
+                    min_x.min(point.x()),
+                    min_y.min(point.y()),
+                    max_x.max(point.x()),
+                    max_y.max(point.y()),
+                ],
+                None => [point.x(), point.y(), point.x(), point.y()],
+            });
+        }
+    }
+    if geographic {
+        // Enlarging the box would let longitudes pass as latitudes
+        return Ok(bounds);
+    }
+    Ok(bounds.map(|[min_x, min_y, max_x, max_y]| {
+        let (margin_x, margin_y) = ((max_x - min_x) * MARGIN, (max_y - min_y) * MARGIN);
+        [
+            min_x - margin_x,
+            min_y - margin_y,
+            max_x + margin_x,
+            max_y + margin_y,
+        ]
+    }))
+}
+
+/// Guess whether `sample_points` in `crs` have their horizontal axes swapped
+///
+/// Swapped latitude and longitude is the most common cause of wrongly transformed data, and can
+/// go unnoticed when the results are still valid coordinates. This compares the points with the
+/// CRS's area of use, both as they are and swapped, and reports the
+/// [`AxisOrder`](enum.AxisOrder.html) which fits more of them, along with the CRS's own axis
+/// order. A transformation created with the default, normalised, axis order expects
+/// `AxisOrder::EastNorth`.
+///
+/// The guess is only as good as the sample: points which fit the area of use either way, e.g.
+/// latitudes and longitudes which are both within ±90 degrees in a CRS with a global area of
+/// use, don't count towards the confidence. A CRS without an area of use, such as one defined by a
+/// PROJ string, is treated as global if it's geographic, and otherwise gives a confidence of 0.
+///
+/// ```rust
+/// use proj::{detect_axis_order, AxisOrder};
+/// extern crate geo_types;
+/// use geo_types::Point;
+///
+/// // Sydney, as latitude, longitude
+/// let points = [Point::new(-33.87, 151.21), Point::new(-33.92, 151.17)];
+/// let guess = detect_axis_order(&points, "EPSG:4326").unwrap();
+/// assert_eq!(guess.order, AxisOrder::NorthEast);
+/// assert_eq!(guess.confidence, 1.0);
+/// assert_eq!(guess.crs_order, AxisOrder::NorthEast);
+/// ```
+pub fn detect_axis_order<T: Float>(
+    sample_points: &[Point<T>],
+    crs: &str,
+) -> Result<AxisOrderGuess, ProjError> {
+    let proj = Proj::new(crs)?;
+    let (geographic, axes) = proj.output_axes()?;
+    let crs_order = match axes[0].direction.as_str() {
+        "north" | "south" => AxisOrder::NorthEast,
+        _ => AxisOrder::EastNorth,
+    };
+    let area = match proj.area_of_use()?.0 {
+        Some(area) => Some(area),
+        None if geographic => Some(Area::new(-180.0, -90.0, 180.0, 90.0)?),
+        None => None,
+    };
+    let bounds = match area {
+        Some(area) => crs_bounds(&area, crs, geographic)?,
+        None => None,
+    };
+    let mut guess = AxisOrderGuess {
+        order: AxisOrder::EastNorth,
+        confidence: 0.0,
+        crs_order,
+    };
+    let [min_x, min_y, max_x, max_y] = match bounds {
+        Some(bounds) => bounds,
+        None => return Ok(guess),
+    };
+    let fits = |x: f64, y: f64| (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+    let (mut east_north, mut north_east, mut count) = (0usize, 0usize, 0usize);
+    for point in sample_points {
+        let (x, y) = match (point.x().to_f64(), point.y().to_f64()) {
+            (Some(x), Some(y)) if x.is_finite() && y.is_finite() => (x, y),
+            _ => continue,
+        };
+        count += 1;
+        match (fits(x, y), fits(y, x)) {
+            (true, false) => east_north += 1,
+            (false, true) => north_east += 1,
+            _ => (),
+        }
+    }
+    if count > 0 {
+        if north_east > east_north {
+            guess.order = AxisOrder::NorthEast;
+        }
+        guess.confidence = east_north.abs_diff(north_east) as f64 / count as f64;
+    }
+    Ok(guess)
+}
@@ -0,0 +1,258 @@
+//! A small `cs2cs`-like tool, for transforming coordinates without the PROJ command line tools
+//!
+//! Coordinates are read from standard input, one point per line as `x y [z [t]]`, and written to
+//! standard output with the same dimensions. Any text following the coordinates is passed
+//! through. Points which can't be transformed are written as `*`, and the error is reported on
+//! standard error.
+//!
+//! ```text
+//! rust-cs2cs [-f <format>] <source CRS> <target CRS>
+//! rust-cs2cs [-f <format>] <source CRS arguments> +to <target CRS arguments>
+//! rust-cs2cs [-f <format>] +proj=<operation> [<arguments>...]
+//! rust-cs2cs info <CRS> [<target CRS>]
+//! ```
+//!
+//! `<format>` is a `printf`-style format for the output coordinates: `%f`, `%e`, or either with a
+//! precision, e.g. `%.3f`.
+//!
+//! The `info` mode describes a CRS, as `projinfo` does: its WKT2 and PROJJSON definitions, its
+//! area of use and, if a target CRS is given, the candidate operations between them, with their
+//! accuracies and the grids they require.
+use proj::{Area, Explanation, Info, Proj, ProjBuilder, ProjCoord};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "\
+usage: rust-cs2cs [-f <format>] <source CRS> <target CRS>
+       rust-cs2cs [-f <format>] <source CRS arguments> +to <target CRS arguments>
+       rust-cs2cs [-f <format>] +proj=<operation> [<arguments>...]
+       rust-cs2cs info <CRS> [<target CRS>]
+
+Reads `x y [z [t]]` coordinates from standard input, and writes the transformed coordinates to
+standard output. <format> is a printf-style format such as %.3f or %e.
+
+The info mode describes a CRS, and the candidate operations to a target CRS.";
+
+/// How output coordinates are written
+#[derive(Clone, Copy, Default)]
+struct Format {
+    precision: Option<usize>,
+    exponent: bool,
+}
+
+impl Format {
+    /// Parse a `printf`-style format: `%f` or `%e`, with an optional precision
+    fn parse(format: &str) -> Option<Format> {
+        let spec = format.strip_prefix('%')?;
+        let (precision, conversion) = match spec.strip_prefix('.') {
+            Some(rest) => {
+                let (digits, conversion) = rest.split_at(rest.len().checked_sub(1)?);
+                (digits.parse().ok()?, conversion)
+            }
+            // printf's default precision
+            None => (6, spec),
+        };
+        let exponent = match conversion {
+            "f" => false,
+            "e" => true,
+            _ => return None,
+        };
+        Some(Format {
+            precision: Some(precision),
+            exponent,
+        })
+    }
+
+    fn write(self, value: f64) -> String {
+        match (self.precision, self.exponent) {
+            (Some(precision), false) => format!("{:.*}", precision, value),
+            (Some(precision), true) => format!("{:.*e}", precision, value),
+            (None, false) => format!("{}", value),
+            (None, true) => format!("{:e}", value),
+        }
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("rust-cs2cs: {}", message);
+    process::exit(1);
+}
+
+/// Create the transformation described by the command line arguments
+fn transformation(args: &[String]) -> Result<Proj, String> {
+    let transformation = if let Some(to) = args.iter().position(|arg| arg == "+to") {
+        let (source, target) = (args[..to].join(" "), args[to + 1..].join(" "));
+        Proj::new_known_crs(&source, &target, None)
+    } else if args.first().is_some_and(|arg| arg.starts_with('+')) {
+        Proj::new_from_args(args)
+    } else if args.len() == 2 {
+        Proj::new_known_crs(&args[0], &args[1], None)
+    } else {
+        return Err(USAGE.to_string());
+    };
+    transformation.map_err(|err| err.to_string())
+}
+
+/// Split a line into a coordinate and the text following it
+fn parse_line(line: &str) -> Option<(ProjCoord, Vec<&str>)> {
+    let mut values = vec![];
+    let mut tokens = line.split_whitespace().peekable();
+    while values.len() < 4 {
+        match tokens.peek().and_then(|token| token.parse::<f64>().ok()) {
+            Some(value) => {
+                values.push(value);
+                tokens.next();
+            }
+            None => break,
+        }
+    }
+    let coord = match values[..] {
+        [x, y] => ProjCoord::new_2d(x, y),
+        [x, y, z] => ProjCoord::new_3d(x, y, z),
+        [x, y, z, t] => ProjCoord::new_4d(x, y, z, t),
+        _ => return None,
+    };
+    Some((coord, tokens.collect()))
+}
+
+/// Write a coordinate as `cs2cs` does, with a tab after `x`
+fn write_coord(coord: &ProjCoord, format: Format) -> String {
+    let mut output = format!("{}\t{}", format.write(coord.x), format.write(coord.y));
+    for value in coord.z.iter().chain(coord.t.iter()) {
+        output.push(' ');
+        output.push_str(&format.write(*value));
+    }
+    output
+}
+
+fn describe_area(area: Option<Area>, name: Option<String>) -> String {
+    match area {
+        Some(area) => format!(
+            "{} ({}, {}, {}, {})",
+            name.unwrap_or_default(),
+            area.west(),
+            area.south(),
+            area.east(),
+            area.north()
+        ),
+        None => "unknown".to_string(),
+    }
+}
+
+fn describe_operation(number: usize, operation: Explanation) -> String {
+    let mut description = format!("{}. {}\n", number, operation.name);
+    let accuracy = match operation.accuracy {
+        Some(accuracy) => format!("{} m", accuracy),
+        None => "unknown".to_string(),
+    };
+    let ballpark = if operation.ballpark {
+        " (ballpark)"
+    } else {
+        ""
+    };
+    description += &format!("   accuracy: {}{}\n", accuracy, ballpark);
+    description += &format!(
+        "   area of use: {}\n",
+        describe_area(operation.area_of_use, operation.area_name)
+    );
+    if let Some(definition) = operation.definition {
+        description += &format!("   definition: {}\n", definition);
+    }
+    for grid in operation.grids {
+        let availability = if grid.available {
+            "available".to_string()
+        } else {
+            format!("missing, from {}", grid.url)
+        };
+        description += &format!("   grid: {} ({})\n", grid.name, availability);
+    }
+    description
+}
+
+/// Describe a CRS, and the candidate operations to `target`, as `projinfo` does
+fn info(args: &[String]) -> Result<(), String> {
+    let (crs, target) = match args {
+        [crs] => (crs, None),
+        [crs, target] => (crs, Some(target)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let proj = Proj::new(crs).map_err(|err| err.to_string())?;
+    let wkt = proj.to_wkt().map_err(|err| err.to_string())?;
+    println!("WKT2:\n{}\n", wkt);
+    let projjson = proj.to_projjson().map_err(|err| err.to_string())?;
+    println!("PROJJSON:\n{}\n", projjson);
+    let (area, name) = proj.area_of_use().map_err(|err| err.to_string())?;
+    println!("Area of use: {}", describe_area(area, name));
+    if let Some(target) = target {
+        let operations = ProjBuilder::new()
+            .candidate_operations(crs, target, None)
+            .map_err(|err| err.to_string())?;
+        println!(
+            "\nCandidate operations to {}: {}\n",
+            target,
+            operations.len()
+        );
+        for (number, operation) in operations.into_iter().enumerate() {
+            println!("{}", describe_operation(number + 1, operation));
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    if args.first().map(String::as_str) == Some("info") {
+        info(&args[1..]).unwrap_or_else(|err| fail(&err));
+        return;
+    }
+    let mut format = Format::default();
+    if let Some(flag) = args.iter().position(|arg| arg == "-f") {
+        let spec = args
+            .get(flag + 1)
+            .cloned()
+            .unwrap_or_else(|| fail("-f requires a format"));
+        format =
+            Format::parse(&spec).unwrap_or_else(|| fail(&format!("unsupported format {}", spec)));
+        args.drain(flag..flag + 2);
+    }
+    let proj = transformation(&args).unwrap_or_else(|err| fail(&err));
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    for (number, line) in stdin.lock().lines().enumerate() {
+        let line = line.unwrap_or_else(|err| fail(&err.to_string()));
+        let trimmed = line.trim();
+        // Blank lines and comments are passed through
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            writeln!(output, "{}", line).unwrap_or_else(|err| fail(&err.to_string()));
+            continue;
+        }
+        let (coord, rest) = match parse_line(trimmed) {
+            Some(parsed) => parsed,
+            None => {
+                eprintln!("line {}: expected x y [z [t]]", number + 1);
+                continue;
+            }
+        };
+        let transformed = match proj.convert_coord(coord) {
+            Ok(transformed) => write_coord(&transformed, format),
+            Err(err) => {
+                eprintln!("line {}: {}", number + 1, err);
+                let stars = coord.z.iter().chain(coord.t.iter()).map(|_| " *");
+                std::iter::once("*\t*").chain(stars).collect()
+            }
+        };
+        let result = if rest.is_empty() {
+            writeln!(output, "{}", transformed)
+        } else {
+            writeln!(output, "{} {}", transformed, rest.join(" "))
+        };
+        result.unwrap_or_else(|err| fail(&err.to_string()));
+    }
+    output.flush().unwrap_or_else(|err| fail(&err.to_string()));
+}
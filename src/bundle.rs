@@ -0,0 +1,75 @@
+//! Grid bundles: collections of grids which can be copied to machines without network access
+use crate::proj::ProjError;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Whether a bundle is a tar archive, rather than a directory
+fn is_archive(bundle: &Path) -> bool {
+    bundle.extension() == Some(OsStr::new("tar"))
+}
+
+fn bundle_error(bundle: &Path) -> impl Fn(io::Error) -> ProjError + '_ {
+    move |e| ProjError::GridBundle(bundle.display().to_string(), e)
+}
+
+/// Copy grids into a new bundle
+pub(crate) fn export(grids: &[PathBuf], bundle: &Path) -> Result<(), ProjError> {
+    // Check that every grid is present before creating the bundle
+    for grid in grids {
+        fs::metadata(grid).map_err(|e| ProjError::GridRead(grid.display().to_string(), e))?;
+    }
+    let error = bundle_error(bundle);
+    if is_archive(bundle) {
+        let mut archive = tar::Builder::new(File::create(bundle).map_err(&error)?);
+        for grid in grids {
+            let name = grid.file_name().ok_or(ProjError::Path)?;
+            let mut file =
+                File::open(grid).map_err(|e| ProjError::GridRead(grid.display().to_string(), e))?;
+            archive.append_file(name, &mut file).map_err(&error)?;
+        }
+        archive.finish().map_err(&error)?;
+    } else {
+        fs::create_dir_all(bundle).map_err(&error)?;
+        for grid in grids {
+            let name = grid.file_name().ok_or(ProjError::Path)?;
+            fs::copy(grid, bundle.join(name))
+                .map_err(|e| ProjError::GridRead(grid.display().to_string(), e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Copy the grids in a bundle into `dir`, returning their names
+///
+/// Only regular files at the top level of the bundle are installed, so a bundle can't write
+/// outside `dir`.
+pub(crate) fn import(bundle: &Path, dir: &Path) -> Result<Vec<String>, ProjError> {
+    let error = bundle_error(bundle);
+    let mut names = vec![];
+    if is_archive(bundle) {
+        let mut archive = tar::Archive::new(File::open(bundle).map_err(&error)?);
+        for entry in archive.entries().map_err(&error)? {
+            let mut entry = entry.map_err(&error)?;
+            let path = entry.path().map_err(&error)?.into_owned();
+            let name = match (entry.header().entry_type().is_file(), path.file_name()) {
+                (true, Some(name)) if path.parent() == Some(Path::new("")) => name.to_owned(),
+                _ => continue,
+            };
+            entry.unpack(dir.join(&name)).map_err(&error)?;
+            names.push(name.to_string_lossy().into_owned());
+        }
+    } else {
+        for entry in fs::read_dir(bundle).map_err(&error)? {
+            let entry = entry.map_err(&error)?;
+            if !entry.file_type().map_err(&error)?.is_file() {
+                continue;
+            }
+            fs::copy(entry.path(), dir.join(entry.file_name())).map_err(&error)?;
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
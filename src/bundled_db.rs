@@ -0,0 +1,30 @@
+//! Opening the copy of `proj.db` embedded by the `bundled_db` feature
+//!
+//! PROJ opens its database with SQLite, which needs a file, so the embedded copy is written to the
+//! temporary directory the first time a context is created, and shared by later contexts and
+//! processes.
+use proj_sys::{proj_context_set_database_path, PJ_CONTEXT, PROJ_DB};
+use std::ffi::CString;
+use std::ptr;
+use std::sync::OnceLock;
+
+static DATABASE_PATH: OnceLock<Option<CString>> = OnceLock::new();
+
+/// Point a new context at the embedded database
+pub(crate) fn set_database(ctx: *mut PJ_CONTEXT) {
+    let path = DATABASE_PATH.get_or_init(|| {
+        if PROJ_DB.is_empty() {
+            return None;
+        }
+        match crate::embedded::extract("proj.db", PROJ_DB) {
+            Ok(path) => CString::new(path.to_string_lossy().into_owned()).ok(),
+            Err(e) => {
+                log::warn!(target: "proj", "Couldn't write the embedded proj.db: {}", e);
+                None
+            }
+        }
+    });
+    if let Some(path) = path {
+        unsafe { proj_context_set_database_path(ctx, path.as_ptr(), ptr::null(), ptr::null()) };
+    }
+}
@@ -0,0 +1,31 @@
+//! Finding the grid files embedded by the `bundled_grids` feature
+//!
+//! The grids named in `PROJ_BUNDLED_GRIDS` at build time are embedded in the binary. PROJ reads
+//! grids from files, so each is written to the temporary directory the first time it's needed,
+//! and a file finder hands PROJ the path when it asks for the grid by name.
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+include!(concat!(env!("OUT_DIR"), "/bundled_grids.rs"));
+
+static EXTRACTED: Mutex<Option<HashMap<&'static str, PathBuf>>> = Mutex::new(None);
+
+/// The names of the grid files embedded by the `bundled_grids` feature
+pub fn bundled_grids() -> Vec<&'static str> {
+    BUNDLED_GRIDS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Write the embedded grids to disk, returning the path of each by name
+pub(crate) fn extract() -> std::io::Result<HashMap<&'static str, PathBuf>> {
+    let mut extracted = EXTRACTED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(paths) = extracted.as_ref() {
+        return Ok(paths.clone());
+    }
+    let paths = BUNDLED_GRIDS
+        .iter()
+        .map(|(name, bytes)| Ok((*name, crate::embedded::extract(name, bytes)?)))
+        .collect::<std::io::Result<HashMap<_, _>>>()?;
+    *extracted = Some(paths.clone());
+    Ok(paths)
+}
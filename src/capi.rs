@@ -0,0 +1,99 @@
+//! A C ABI for creating and using transformations, for C and C++ applications which embed Rust
+//!
+//! The functions are exported from any static or dynamic library which links this crate with the
+//! `capi` feature enabled, and can be declared in C as:
+//!
+//! ```c
+//! typedef struct ProjRs ProjRs;
+//!
+//! ProjRs *proj_rs_create_crs_to_crs(const char *from, const char *to, int enable_network);
+//! int proj_rs_transform_array(const ProjRs *proj, double *x, double *y, size_t len);
+//! void proj_rs_destroy(ProjRs *proj);
+//! ```
+//!
+//! Transformations use PROJ through this crate, so with the `network` feature, grids are
+//! downloaded using its own HTTP client, and the application doesn't need to link a
+//! libcurl-enabled PROJ.
+use crate::proj::{Proj, ProjBuilder, ProjError};
+use libc::{c_char, c_int};
+use std::ffi::CStr;
+
+fn create_crs_to_crs(from: &CStr, to: &CStr, enable_network: bool) -> Result<Proj, ProjError> {
+    let from = from.to_str()?;
+    let to = to.to_str()?;
+    let builder = ProjBuilder::new();
+    #[cfg(feature = "network")]
+    builder.enable_network(enable_network)?;
+    #[cfg(not(feature = "network"))]
+    let _ = enable_network;
+    builder.proj_known_crs(from, to, None)
+}
+
+/// Create a transformation between two CRS, with coordinates in Longitude, Latitude / Easting,
+/// Northing order, as [`Proj::new_known_crs`](../struct.Proj.html#method.new_known_crs) does
+///
+/// Grid downloads are enabled if `enable_network` is nonzero and the `network` feature is
+/// enabled. Returns null if the transformation can't be created. The transformation must be freed
+/// with [`proj_rs_destroy`](fn.proj_rs_destroy.html).
+///
+/// # Safety
+/// `from` and `to` must be null or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn proj_rs_create_crs_to_crs(
+    from: *const c_char,
+    to: *const c_char,
+    enable_network: c_int,
+) -> *mut Proj {
+    if from.is_null() || to.is_null() {
+        return std::ptr::null_mut();
+    }
+    match create_crs_to_crs(
+        CStr::from_ptr(from),
+        CStr::from_ptr(to),
+        enable_network != 0,
+    ) {
+        Ok(proj) => Box::into_raw(Box::new(proj)),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Transform `len` coordinates in place, using
+/// [`Proj::convert_columns`](../struct.Proj.html#method.convert_columns)
+///
+/// Returns 0 on success, and -1 if the transformation failed. As with `convert_columns`,
+/// coordinates which can't be transformed are set to infinity.
+///
+/// # Safety
+/// `proj` must be null or have been returned by
+/// [`proj_rs_create_crs_to_crs`](fn.proj_rs_create_crs_to_crs.html), and not yet destroyed. `x`
+/// and `y` must each be null or point to `len` doubles.
+#[no_mangle]
+pub unsafe extern "C" fn proj_rs_transform_array(
+    proj: *const Proj,
+    x: *mut f64,
+    y: *mut f64,
+    len: usize,
+) -> c_int {
+    if proj.is_null() || x.is_null() || y.is_null() {
+        return -1;
+    }
+    let x = std::slice::from_raw_parts_mut(x, len);
+    let y = std::slice::from_raw_parts_mut(y, len);
+    match (*proj).convert_columns(x, y, None) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Free a transformation created by
+/// [`proj_rs_create_crs_to_crs`](fn.proj_rs_create_crs_to_crs.html)
+///
+/// # Safety
+/// `proj` must be null or have been returned by `proj_rs_create_crs_to_crs`, and not yet
+/// destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn proj_rs_destroy(proj: *mut Proj) {
+    if !proj.is_null() {
+        drop(Box::from_raw(proj));
+    }
+}
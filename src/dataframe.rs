@@ -0,0 +1,89 @@
+use crate::proj::{Proj, ProjError};
+use polars::prelude::{Float64Chunked, NewChunkedArray};
+
+/// A column's values, with nulls as NaN
+fn values(column: &Float64Chunked) -> Vec<f64> {
+    column.iter().map(|v| v.unwrap_or(f64::NAN)).collect()
+}
+
+/// Build an output column with the same name as `input`, with null rows where `valid` is false
+fn column(input: &Float64Chunked, values: Vec<f64>, valid: &[bool]) -> Float64Chunked {
+    Float64Chunked::from_iter_options(
+        input.name().clone(),
+        values
+            .into_iter()
+            .zip(valid)
+            .map(|(value, valid)| Some(value).filter(|_| *valid)),
+    )
+}
+
+impl Proj {
+    /// Convert [`polars`](https://docs.rs/polars) columns of x and y coordinates
+    ///
+    /// The columns are converted in one batch, using
+    /// [`convert_columns`](#method.convert_columns). The returned columns keep the input columns'
+    /// names. A row is null in the output if either of its input coordinates is null, or if it
+    /// couldn't be converted.
+    ///
+    /// ```rust
+    /// use polars::prelude::*;
+    /// use proj::Proj;
+    ///
+    /// let lon = Float64Chunked::from_slice("lon".into(), &[10.0, 11.0]);
+    /// let lat = Float64Chunked::from_slice("lat".into(), &[50.0, 51.0]);
+    /// let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+    /// let (x, y) = proj.convert_chunked(&lon, &lat).unwrap();
+    /// assert!((x.get(0).unwrap() - 1113194.9).abs() < 0.1);
+    /// ```
+    pub fn convert_chunked(
+        &self,
+        x: &Float64Chunked,
+        y: &Float64Chunked,
+    ) -> Result<(Float64Chunked, Float64Chunked), ProjError> {
+        let (x, y, _) = self.chunked(x, y, None)?;
+        Ok((x, y))
+    }
+
+    /// Like [`convert_chunked`](#method.convert_chunked), but also converting a column of heights
+    pub fn convert_chunked_3d(
+        &self,
+        x: &Float64Chunked,
+        y: &Float64Chunked,
+        z: &Float64Chunked,
+    ) -> Result<(Float64Chunked, Float64Chunked, Float64Chunked), ProjError> {
+        let (x, y, z) = self.chunked(x, y, Some(z))?;
+        Ok((x, y, z.expect("a z column was converted")))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn chunked(
+        &self,
+        x_in: &Float64Chunked,
+        y_in: &Float64Chunked,
+        z_in: Option<&Float64Chunked>,
+    ) -> Result<(Float64Chunked, Float64Chunked, Option<Float64Chunked>), ProjError> {
+        let mut x = values(x_in);
+        let mut y = values(y_in);
+        let mut z = z_in.map(values);
+        self.convert_columns(&mut x, &mut y, z.as_deref_mut())?;
+        let valid: Vec<bool> = x_in
+            .iter()
+            .zip(y_in.iter())
+            .zip(&x)
+            .zip(&y)
+            .enumerate()
+            .map(|(i, (((x_in, y_in), x), y))| {
+                x_in.is_some()
+                    && y_in.is_some()
+                    && z_in.is_none_or(|z_in| z_in.get(i).is_some())
+                    && x.is_finite()
+                    && y.is_finite()
+            })
+            .collect();
+        Ok((
+            column(x_in, x, &valid),
+            column(y_in, y, &valid),
+            z_in.zip(z).map(|(z_in, z)| column(z_in, z, &valid)),
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+//! Writing files embedded in the binary to disk, for PROJ to open
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An FNV-1a hash of a file, distinguishing the copies written by different builds
+fn fingerprint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The directory the embedded files of this build are written to, created if necessary
+///
+/// The temporary directory is shared by all users, so each has a directory of their own, which
+/// only they can write to. An existing directory is refused unless it's the user's and nobody else
+/// can write to it, as another user could otherwise have planted a different database or grid.
+#[cfg(unix)]
+fn private_dir(fingerprint: u64) -> io::Result<PathBuf> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+    let uid = unsafe { libc::getuid() };
+    let dir = std::env::temp_dir().join(format!("proj-{}-{:016x}", uid, fingerprint));
+    match fs::DirBuilder::new().mode(0o700).create(&dir) {
+        Err(e) if e.kind() != io::ErrorKind::AlreadyExists => return Err(e),
+        _ => {}
+    }
+    let metadata = fs::symlink_metadata(&dir)?;
+    if !metadata.is_dir() || metadata.uid() != uid || metadata.permissions().mode() & 0o077 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} isn't private to this user", dir.display()),
+        ));
+    }
+    Ok(dir)
+}
+
+/// The directory the embedded files of this build are written to, created if necessary
+///
+/// The temporary directory is already private to the user on Windows.
+#[cfg(not(unix))]
+fn private_dir(fingerprint: u64) -> io::Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("proj-{:016x}", fingerprint));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Whether `path` holds exactly `bytes`
+fn matches(path: &Path, bytes: &[u8]) -> bool {
+    fs::read(path).is_ok_and(|existing| existing == bytes)
+}
+
+/// Write an embedded file to the temporary directory, unless an earlier process already has
+pub(crate) fn extract(name: &str, bytes: &[u8]) -> io::Result<PathBuf> {
+    let dir = private_dir(fingerprint(bytes))?;
+    let path = dir.join(name);
+    if !matches(&path, bytes) {
+        // Write to a file of our own first, so other processes never open a partial file
+        let partial = dir.join(format!("{}.{}.partial", name, std::process::id()));
+        fs::write(&partial, bytes)?;
+        fs::rename(&partial, &path)?;
+    }
+    Ok(path)
+}
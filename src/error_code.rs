@@ -0,0 +1,150 @@
+//! The reasons PROJ gives for failing to transform coordinates
+use libc::c_int;
+
+/// The broad category of an [`ErrorCode`](enum.ErrorCode.html), as PROJ groups its error codes
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// The operation is invalid, e.g. a parameter is missing, or a grid couldn't be found
+    InvalidOperation,
+    /// The operation is valid, but a coordinate couldn't be transformed with it
+    CoordinateTransformation,
+    /// Other errors, e.g. misuse of the API or network errors
+    Other,
+}
+
+/// The reason PROJ gave for an error, as reported by
+/// [`ProjError::code`](enum.ProjError.html#method.code)
+///
+/// The variants correspond to the `PROJ_ERR_*` constants of PROJ 8 and later. The error numbers
+/// of earlier versions are mapped to the closest variant.
+///
+/// ```rust
+/// use proj::{ErrorCategory, ErrorCode, Proj};
+/// extern crate geo_types;
+/// use geo_types::Point;
+///
+/// let geos = Proj::new(
+///     "+proj=geos +lon_0=0.00 +lat_0=0.00 +a=6378169.00 +b=6356583.80 +h=35785831.0",
+/// )
+/// .unwrap();
+/// let error = geos
+///     .convert::<_, f64>(Point::new(4760096.421921, 3744293.729449))
+///     .unwrap_err();
+/// let code = error.code().unwrap();
+/// assert_eq!(code, ErrorCode::InvalidCoordinate);
+/// assert_eq!(code.category(), ErrorCategory::CoordinateTransformation);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// `PROJ_ERR_INVALID_OP`: the operation is invalid for an unspecified reason
+    InvalidOperation,
+    /// `PROJ_ERR_INVALID_OP_WRONG_SYNTAX`: the operation's definition is malformed
+    WrongSyntax,
+    /// `PROJ_ERR_INVALID_OP_MISSING_ARG`: a required parameter is missing
+    MissingArgument,
+    /// `PROJ_ERR_INVALID_OP_ILLEGAL_ARG_VALUE`: a parameter has an invalid value
+    IllegalArgumentValue,
+    /// `PROJ_ERR_INVALID_OP_MUTUALLY_EXCLUSIVE_ARGS`: parameters which can't be combined were
+    /// given
+    MutuallyExclusiveArguments,
+    /// `PROJ_ERR_INVALID_OP_FILE_NOT_FOUND_OR_INVALID`: a file, such as a grid, is missing or
+    /// invalid
+    FileNotFoundOrInvalid,
+    /// `PROJ_ERR_COORD_TRANSFM`: a coordinate couldn't be transformed for an unspecified reason
+    CoordinateTransformation,
+    /// `PROJ_ERR_COORD_TRANSFM_INVALID_COORD`: the coordinate is invalid, e.g. a latitude beyond
+    /// 90 degrees, which usually indicates a problem with the data
+    InvalidCoordinate,
+    /// `PROJ_ERR_COORD_TRANSFM_OUTSIDE_PROJECTION_DOMAIN`: the coordinate is valid, but outside
+    /// the region the projection can represent
+    OutsideProjectionDomain,
+    /// `PROJ_ERR_COORD_TRANSFM_NO_OPERATION`: none of a CRS-to-CRS instance's operations can
+    /// transform the coordinate
+    NoOperation,
+    /// `PROJ_ERR_COORD_TRANSFM_OUTSIDE_GRID`: the coordinate is outside the grids the operation
+    /// uses, so a less accurate operation, e.g. a ballpark one, may be used instead
+    OutsideGrid,
+    /// `PROJ_ERR_COORD_TRANSFM_GRID_AT_NODATA`: the grid has no value at the coordinate
+    GridAtNodata,
+    /// `PROJ_ERR_COORD_TRANSFM_NO_CONVERGENCE`: an iterative computation didn't converge
+    NoConvergence,
+    /// `PROJ_ERR_COORD_TRANSFM_MISSING_TIME`: the operation requires a time coordinate
+    MissingTime,
+    /// `PROJ_ERR_OTHER`: an unspecified error
+    Other,
+    /// `PROJ_ERR_OTHER_API_MISUSE`: PROJ's API was misused
+    ApiMisuse,
+    /// `PROJ_ERR_OTHER_NO_INVERSE_OP`: the operation has no inverse
+    NoInverseOperation,
+    /// `PROJ_ERR_OTHER_NETWORK_ERROR`: a network request failed
+    NetworkError,
+    /// An error number this crate doesn't recognise
+    Unknown(i32),
+}
+
+// PROJ 8's error numbers. Each category's first number is its generic error
+const INVALID_OP: c_int = 1024;
+const COORD_TRANSFM: c_int = 2048;
+const OTHER: c_int = 4096;
+
+impl ErrorCode {
+    /// The `ErrorCode` of an error number reported by `proj_errno` or `proj_context_errno`
+    pub fn from_errno(errno: c_int) -> ErrorCode {
+        match errno {
+            INVALID_OP => ErrorCode::InvalidOperation,
+            1025 => ErrorCode::WrongSyntax,
+            1026 => ErrorCode::MissingArgument,
+            1027 => ErrorCode::IllegalArgumentValue,
+            1028 => ErrorCode::MutuallyExclusiveArguments,
+            1029 => ErrorCode::FileNotFoundOrInvalid,
+            COORD_TRANSFM => ErrorCode::CoordinateTransformation,
+            2049 => ErrorCode::InvalidCoordinate,
+            2050 => ErrorCode::OutsideProjectionDomain,
+            2051 => ErrorCode::NoOperation,
+            2052 => ErrorCode::OutsideGrid,
+            2053 => ErrorCode::GridAtNodata,
+            2054 => ErrorCode::NoConvergence,
+            2055 => ErrorCode::MissingTime,
+            OTHER => ErrorCode::Other,
+            4097 => ErrorCode::ApiMisuse,
+            4098 => ErrorCode::NoInverseOperation,
+            4099 => ErrorCode::NetworkError,
+            // Before PROJ 8, errors had negative numbers, which PROJ 8 maps onto the above
+            -14 | -15 => ErrorCode::InvalidCoordinate,
+            -19 | -20 => ErrorCode::OutsideProjectionDomain,
+            -17 | -18 | -53 => ErrorCode::NoConvergence,
+            -38 => ErrorCode::FileNotFoundOrInvalid,
+            -48 => ErrorCode::OutsideGrid,
+            -50 | -44 => ErrorCode::WrongSyntax,
+            -54 => ErrorCode::MissingArgument,
+            -58 => ErrorCode::IllegalArgumentValue,
+            -59..=-1 => ErrorCode::InvalidOperation,
+            _ => ErrorCode::Unknown(errno),
+        }
+    }
+
+    /// The category of the error, or `None` if it isn't recognised
+    pub fn category(self) -> Option<ErrorCategory> {
+        match self {
+            ErrorCode::InvalidOperation
+            | ErrorCode::WrongSyntax
+            | ErrorCode::MissingArgument
+            | ErrorCode::IllegalArgumentValue
+            | ErrorCode::MutuallyExclusiveArguments
+            | ErrorCode::FileNotFoundOrInvalid => Some(ErrorCategory::InvalidOperation),
+            ErrorCode::CoordinateTransformation
+            | ErrorCode::InvalidCoordinate
+            | ErrorCode::OutsideProjectionDomain
+            | ErrorCode::NoOperation
+            | ErrorCode::OutsideGrid
+            | ErrorCode::GridAtNodata
+            | ErrorCode::NoConvergence
+            | ErrorCode::MissingTime => Some(ErrorCategory::CoordinateTransformation),
+            ErrorCode::Other
+            | ErrorCode::ApiMisuse
+            | ErrorCode::NoInverseOperation
+            | ErrorCode::NetworkError => Some(ErrorCategory::Other),
+            ErrorCode::Unknown(_) => None,
+        }
+    }
+}
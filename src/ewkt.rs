@@ -0,0 +1,112 @@
+//! Reprojecting [`wkt`](https://docs.rs/wkt) geometries, including EWKT geometries with an embedded SRID
+//!
+//! ```rust
+//! use proj::ewkt::Ewkt;
+//!
+//! let ewkt: Ewkt = "SRID=4326;POINT(10 50)".parse().unwrap();
+//! let web_mercator = ewkt.reproject(3857).unwrap();
+//! assert!(web_mercator.to_string().starts_with("SRID=3857;POINT(1113194.9"));
+//! ```
+use crate::proj::{Proj, ProjError};
+use geo_types::Point;
+use std::fmt;
+use std::str::FromStr;
+use wkt::types::Coord;
+use wkt::{Geometry, Wkt};
+
+/// A WKT geometry, optionally preceded by the EPSG code of its CRS (e.g. `SRID=4326;POINT(10 50)`)
+#[derive(Clone, Debug)]
+pub struct Ewkt {
+    pub srid: Option<u32>,
+    pub geometry: Wkt<f64>,
+}
+
+impl Ewkt {
+    /// Reproject the geometry into the CRS with the given EPSG code
+    ///
+    /// Coordinates are in longitude, latitude / easting, northing order, as for
+    /// [`Proj::new_known_crs`](../struct.Proj.html#method.new_known_crs). The geometry must have
+    /// an SRID.
+    pub fn reproject(&self, srid: u32) -> Result<Ewkt, ProjError> {
+        let from = self
+            .srid
+            .ok_or_else(|| ProjError::WktGeometry("the geometry has no SRID".to_string()))?;
+        let proj = Proj::new_known_crs(&format!("EPSG:{}", from), &format!("EPSG:{}", srid), None)?;
+        let mut geometry = self.geometry.clone();
+        proj.convert_wkt(&mut geometry)?;
+        Ok(Ewkt {
+            srid: Some(srid),
+            geometry,
+        })
+    }
+}
+
+impl FromStr for Ewkt {
+    type Err = ProjError;
+
+    fn from_str(ewkt: &str) -> Result<Self, Self::Err> {
+        let invalid = |e: &str| ProjError::WktGeometry(e.to_string());
+        let (srid, wkt) = match ewkt.trim_start().split_once(';') {
+            Some((srid, wkt)) if srid.to_ascii_uppercase().starts_with("SRID=") => {
+                let srid = srid[5..]
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid("invalid SRID"))?;
+                (Some(srid), wkt)
+            }
+            _ => (None, ewkt),
+        };
+        Ok(Ewkt {
+            srid,
+            geometry: wkt.parse().map_err(invalid)?,
+        })
+    }
+}
+
+impl fmt::Display for Ewkt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(srid) = self.srid {
+            write!(f, "SRID={};", srid)?;
+        }
+        write!(f, "{}", self.geometry)
+    }
+}
+
+/// Every coordinate of a geometry
+fn coords_mut(geometry: &mut Geometry<f64>) -> Vec<&mut Coord<f64>> {
+    match geometry {
+        Geometry::Point(point) => point.0.iter_mut().collect(),
+        Geometry::LineString(line) => line.0.iter_mut().collect(),
+        Geometry::Polygon(polygon) => polygon.0.iter_mut().flat_map(|r| r.0.iter_mut()).collect(),
+        Geometry::MultiPoint(points) => points.0.iter_mut().filter_map(|p| p.0.as_mut()).collect(),
+        Geometry::MultiLineString(lines) => {
+            lines.0.iter_mut().flat_map(|l| l.0.iter_mut()).collect()
+        }
+        Geometry::MultiPolygon(polygons) => polygons
+            .0
+            .iter_mut()
+            .flat_map(|p| p.0.iter_mut())
+            .flat_map(|r| r.0.iter_mut())
+            .collect(),
+        Geometry::GeometryCollection(geometries) => {
+            geometries.0.iter_mut().flat_map(coords_mut).collect()
+        }
+    }
+}
+
+impl Proj {
+    /// Convert every coordinate of a [`wkt`](https://docs.rs/wkt) geometry in place
+    ///
+    /// The coordinates are converted in a single batch, as by
+    /// [`convert_array`](#method.convert_array). Z and M values are left unchanged.
+    pub fn convert_wkt(&self, wkt: &mut Wkt<f64>) -> Result<(), ProjError> {
+        let mut coords = coords_mut(&mut wkt.item);
+        let mut points: Vec<Point<f64>> = coords.iter().map(|c| Point::new(c.x, c.y)).collect();
+        self.convert_array(&mut points)?;
+        for (coord, point) in coords.iter_mut().zip(points) {
+            coord.x = point.x();
+            coord.y = point.y();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use libc::{c_char, c_void};
+use proj_sys::PJ_CONTEXT;
+use std::ffi::{CStr, CString};
+use std::path::PathBuf;
+
+type Find = Box<dyn Fn(&str) -> Option<PathBuf>>;
+
+/// A user-supplied callback which locates resource files, and the path it most recently returned
+///
+/// libproj only guarantees that it's finished with a returned path once the finder is called
+/// again, so the path is kept alive here until then.
+pub(crate) struct FileFinder {
+    find: Find,
+    found: Option<CString>,
+}
+
+impl FileFinder {
+    pub(crate) fn new<F>(find: F) -> Self
+    where
+        F: Fn(&str) -> Option<PathBuf> + 'static,
+    {
+        FileFinder {
+            find: Box::new(find),
+            found: None,
+        }
+    }
+}
+
+/// Called by libproj to locate a resource file (e.g. `proj.db`, or a grid)
+///
+/// Returns a null pointer if the callback doesn't know the file, in which case libproj falls
+/// back to searching its usual locations.
+pub(crate) unsafe extern "C" fn find_file(
+    _ctx: *mut PJ_CONTEXT,
+    name: *const c_char,
+    ud: *mut c_void,
+) -> *const c_char {
+    let finder = &mut *(ud as *mut FileFinder);
+    let name = CStr::from_ptr(name).to_string_lossy();
+    finder.found = (finder.find)(&name)
+        .and_then(|path| path.to_str().and_then(|path| CString::new(path).ok()));
+    finder
+        .found
+        .as_ref()
+        .map_or(std::ptr::null(), |path| path.as_ptr())
+}
@@ -0,0 +1,141 @@
+//! Formatting transformed coordinates for display, e.g. in reports and labels
+use crate::angles::radians_to_dms;
+use crate::proj::{Proj, ProjError};
+use geo_types::Point;
+use num_traits::Float;
+
+/// How the values of one axis are formatted
+#[derive(Clone, Debug, PartialEq)]
+enum AxisFormat {
+    /// Longitude, with the factor converting its unit to radians
+    Longitude(f64),
+    /// Latitude, with the factor converting its unit to radians
+    Latitude(f64),
+    /// A linear value, with its unit's abbreviation
+    Linear(String),
+}
+
+impl AxisFormat {
+    fn format(&self, value: f64, decimals: usize) -> String {
+        match self {
+            AxisFormat::Longitude(to_radians) => {
+                radians_to_dms(value * to_radians, Some((b'E', b'W')))
+            }
+            AxisFormat::Latitude(to_radians) => {
+                radians_to_dms(value * to_radians, Some((b'N', b'S')))
+            }
+            AxisFormat::Linear(unit) => format!("{:.*} {}", decimals, value, unit),
+        }
+    }
+}
+
+/// The abbreviation of a linear unit, or its name if it has no common abbreviation
+fn unit_abbreviation(name: &str) -> &str {
+    match name {
+        "metre" | "meter" => "m",
+        "kilometre" | "kilometer" => "km",
+        "foot" => "ft",
+        "US survey foot" => "ftUS",
+        "yard" => "yd",
+        "mile" | "Statute mile" => "mi",
+        "nautical mile" => "nmi",
+        _ => name,
+    }
+}
+
+/// Formats coordinates according to the coordinate system they're in
+///
+/// Geographic coordinates are written in degrees, minutes and seconds with a hemisphere, e.g.
+/// `2d21'E, 48d51'24"N`, and projected coordinates with a fixed number of decimal places and
+/// their unit, e.g. `1450880.29 m, 1141263.01 m`.
+///
+/// ```rust
+/// use proj::{CoordFormatter, Proj};
+/// extern crate geo_types;
+/// use geo_types::Point;
+///
+/// let to_mercator = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// let formatter = CoordFormatter::for_output(&to_mercator).unwrap();
+/// let projected = Point::new(222638.981, 6274861.394);
+/// assert_eq!(formatter.format(projected), "222638.98 m, 6274861.39 m");
+///
+/// let formatter = CoordFormatter::geographic();
+/// assert_eq!(formatter.format(Point::new(2.0, -33.5)), "2dE, 33d30'S");
+///
+/// let mut formatter = CoordFormatter::projected("ftUS");
+/// formatter.set_decimals(1);
+/// let point = Point::new(4760096.42, 3744293.73);
+/// assert_eq!(formatter.format(point), "4760096.4 ftUS, 3744293.7 ftUS");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoordFormatter {
+    axes: [AxisFormat; 2],
+    decimals: usize,
+}
+
+impl CoordFormatter {
+    /// Format coordinates in the CRS which `proj` transforms coordinates into, or the CRS which
+    /// `proj` is
+    ///
+    /// The unit and order of the CRS's axes are taken into account, so that e.g. latitude is
+    /// recognised as the first coordinate of a transformation created with its axis order
+    /// [not normalised](struct.ProjBuilder.html#method.set_normalize_axis_order). If the
+    /// coordinate system isn't known, e.g. for a transformation created from a pipeline, a
+    /// [`NoCoordinateSystem`](enum.ProjError.html#variant.NoCoordinateSystem) error is returned.
+    pub fn for_output(proj: &Proj) -> Result<CoordFormatter, ProjError> {
+        let (geographic, axes) = proj.output_axes()?;
+        let format = |index: usize| {
+            let axis = &axes[index];
+            match axis.direction.as_str() {
+                "east" | "west" if geographic => AxisFormat::Longitude(axis.unit_factor),
+                "north" | "south" if geographic => AxisFormat::Latitude(axis.unit_factor),
+                _ => AxisFormat::Linear(unit_abbreviation(&axis.unit_name).to_string()),
+            }
+        };
+        Ok(CoordFormatter {
+            axes: [format(0), format(1)],
+            decimals: 2,
+        })
+    }
+
+    /// Format longitude, latitude coordinates in degrees
+    pub fn geographic() -> CoordFormatter {
+        let to_radians = 1f64.to_radians();
+        CoordFormatter {
+            axes: [
+                AxisFormat::Longitude(to_radians),
+                AxisFormat::Latitude(to_radians),
+            ],
+            decimals: 2,
+        }
+    }
+
+    /// Format projected coordinates, written with the unit abbreviation `unit`, e.g. `"m"`
+    pub fn projected(unit: &str) -> CoordFormatter {
+        CoordFormatter {
+            axes: [
+                AxisFormat::Linear(unit.to_string()),
+                AxisFormat::Linear(unit.to_string()),
+            ],
+            decimals: 2,
+        }
+    }
+
+    /// Set the number of decimal places which projected coordinates are written with. The
+    /// default is 2
+    ///
+    /// Seconds of geographic coordinates are always rounded to three decimal places, as PROJ does.
+    pub fn set_decimals(&mut self, decimals: usize) {
+        self.decimals = decimals;
+    }
+
+    /// Format a point, e.g. the result of [`convert`](struct.Proj.html#method.convert)
+    pub fn format<T: Float>(&self, point: Point<T>) -> String {
+        let value = |value: T| value.to_f64().unwrap_or(f64::NAN);
+        format!(
+            "{}, {}",
+            self.axes[0].format(value(point.x()), self.decimals),
+            self.axes[1].format(value(point.y()), self.decimals)
+        )
+    }
+}
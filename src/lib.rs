@@ -28,6 +28,8 @@
 //! 1. Both `ProjBuilder` and `Proj` implement the [`Info`](proj/trait.Info.html) trait, which can be used to get information about the current state of the `PROJ` instance;
 //! 2. `Proj::new()` and `ProjBuilder::proj()` have the same signature;
 //! 3. `Proj::new_known_crs()` and `ProjBuilder::proj_known_crs()` have the same signature.
+//! 4. [`Proj::explain()`](proj/struct.Proj.html#method.explain) describes the operation a `Proj`
+//!    uses: its accuracy, pipeline steps, required grids, and area of use.
 //!
 //! ## Network, Cache, and Search Path Functionality
 //!
@@ -39,17 +41,105 @@
 //! Network functionality status can be queried with
 //! `network_enabled`, and the download endpoint can be queried and set using `get_url_endpoint` and `set_url_endpoint`.
 //!
+//! #### Proxies and Network Client Configuration
+//! Grid downloads respect the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+//! An explicit proxy can be set using [`set_network_proxy`](proj/struct.ProjBuilder.html#method.set_network_proxy),
+//! and proxies can be bypassed entirely using [`disable_network_proxy`](proj/struct.ProjBuilder.html#method.disable_network_proxy).
+//! Alternatively, an existing `reqwest` client can be supplied using
+//! [`set_network_client`](proj/struct.ProjBuilder.html#method.set_network_client).
+//! If TLS connections are intercepted, the intercepting CA's certificates can be trusted using
+//! [`add_network_root_certificates`](proj/struct.ProjBuilder.html#method.add_network_root_certificates).
+//! Mirrors requiring authentication can be used by supplying headers using
+//! [`set_network_header`](proj/struct.ProjBuilder.html#method.set_network_header) or
+//! [`set_network_header_callback`](proj/struct.ProjBuilder.html#method.set_network_header_callback).
+//! Requests identify themselves as `proj-rs/x.y.z`; applications can identify themselves instead
+//! using [`set_network_user_agent`](proj/struct.ProjBuilder.html#method.set_network_user_agent).
+//!
+//! #### Object Stores
+//! With the `network-object-store` feature, grids can be read from a private mirror in S3, GCS, or
+//! Azure Blob Storage using [`set_network_object_store_url`](proj/struct.ProjBuilder.html#method.set_network_object_store_url)
+//! or [`set_network_object_store`](proj/struct.ProjBuilder.html#method.set_network_object_store).
+//!
+//! #### Rate Limiting
+//! The rate and concurrency of grid download requests can be limited by passing a
+//! [`RequestLimiter`](struct.RequestLimiter.html) to [`set_network_limiter`](proj/struct.ProjBuilder.html#method.set_network_limiter).
+//! The number of requests can be reduced by coalescing small reads using
+//! [`set_network_readahead`](proj/struct.ProjBuilder.html#method.set_network_readahead).
+//!
+//! #### Download Progress
+//! The progress of grid downloads can be monitored by passing a [`NetworkObserver`](trait.NetworkObserver.html)
+//! to [`set_network_observer`](proj/struct.ProjBuilder.html#method.set_network_observer).
+//!
+//! #### Downloading Complete Grids
+//! Rather than having grid chunks downloaded as transformations require them, complete grids can be
+//! downloaded into the [user-writable directory](https://proj.org/resource_files.html#user-writable-directory)
+//! ahead of time using [`download_grid`](proj/struct.ProjBuilder.html#method.download_grid).
+//! [`Proj::prefetch_grids`](proj/struct.Proj.html#method.prefetch_grids) downloads every grid a
+//! transformation requires within a given area, allowing it to be used offline.
+//! Downloaded grids can be checked against the checksums published by the CDN using
+//! [`verify_grid`](proj/struct.ProjBuilder.html#method.verify_grid).
+//!
+//! To provision machines without network access, downloaded grids can be collected into a
+//! directory or tar archive using [`export_grid_bundle`](proj/struct.ProjBuilder.html#method.export_grid_bundle),
+//! and installed using [`import_grid_bundle`](proj/struct.ProjBuilder.html#method.import_grid_bundle).
+//!
 //! #### Grid File Cache
 //! Up to 300 mb of downloaded grids are cached to save bandwidth: This cache can be enabled or disabled using [`grid_cache_enable`](proj/struct.ProjBuilder.html#method.grid_cache_enable).
+//! How often cached grids are checked for changes can be set using
+//! [`grid_cache_set_revalidation`](proj/struct.ProjBuilder.html#method.grid_cache_set_revalidation).
+//! The size limit and location of the cache can be changed using
+//! [`grid_cache_set_max_size`](proj/struct.ProjBuilder.html#method.grid_cache_set_max_size) and
+//! [`grid_cache_set_filename`](proj/struct.ProjBuilder.html#method.grid_cache_set_filename).
+//! The cache can be emptied using [`grid_cache_clear`](proj/struct.ProjBuilder.html#method.grid_cache_clear),
+//! and its current size reported using [`grid_cache_stats`](proj/struct.ProjBuilder.html#method.grid_cache_stats).
+//!
+//! ### Strict Offline Mode
+//! When a grid is missing, PROJ silently falls back to a less accurate transformation. In
+//! [strict offline mode](proj/struct.ProjBuilder.html#method.set_offline_strict), such
+//! transformations instead fail with [`ProjError::MissingGrid`](enum.ProjError.html#variant.MissingGrid).
 //!
 //! ### Search Path Modification
 //! The path used to search for resource files can be modified using [`set_search_paths`](proj/struct.ProjBuilder.html#method.set_search_paths)
 //!
+//! Applications which ship their own resource files (e.g. embedded in the binary) can locate them
+//! using a callback instead: see [`set_file_finder`](proj/struct.ProjBuilder.html#method.set_file_finder).
+//!
+//! ### Logging
+//! PROJ's diagnostic messages are logged using the [`log`](https://docs.rs/log) crate, with the
+//! `proj` target. By default, only errors are logged; more detail can be requested using
+//! [`set_log_level`](proj/struct.ProjBuilder.html#method.set_log_level).
+//!
+//! ### Contexts
+//! Every `ProjBuilder` and `Proj` owns its own PROJ context, and this crate never uses PROJ's
+//! global default context: there are no free functions which configure it implicitly. Settings
+//! such as network access, the grid endpoint, search paths and the grid cache therefore apply to
+//! a single builder and the `Proj` it creates, never to other instances. To apply the same
+//! settings to several transformations, configure a `ProjBuilder` for each of them, e.g. in a
+//! shared function. Since a context can't be used from several threads at once, there's no
+//! shared, mutex-guarded context either: create a separate `Proj` in each thread instead.
+//!
 //!
 //! # Requirements
 //!
 //! By default, the crate requires `libproj` 7.1.x to be present on your system. While it may be backwards-compatible with older PROJ 6 versions, this is neither tested nor supported.
 //!
+//! The version of `libproj` an application is running against, and its capabilities (e.g. whether
+//! it can read GeoTIFF grids), can be checked at runtime using [`proj_version`](fn.proj_version.html).
+//! The versions of the datasets in PROJ's database (e.g. EPSG) can be checked using
+//! [`database_info`](trait.Info.html#method.database_info).
+//!
+//! [`self_check`](fn.self_check.html) checks that an installation is usable: that `proj.db` can be
+//! opened, the resource file search path exists, the user-writable directory is writable, and
+//! (optionally) that the grid endpoint is reachable.
+//!
+//! Wrappers of APIs added in later PROJ versions are only available when the crate is built
+//! against a `libproj` which has them: [`transform_bounds`](struct.Proj.html#method.transform_bounds)
+//! requires PROJ 8.2, and [`set_only_best`](struct.ProjBuilder.html#method.set_only_best) and
+//! [`coordinate_epoch`](struct.Proj.html#method.coordinate_epoch) require PROJ 9.2.
+//! [`requires_epoch`](struct.Proj.html#method.requires_epoch) only detects point motion operations
+//! with PROJ 9.4. The version is detected at build time, and exposed to the crate as `proj_8_2`,
+//! `proj_9_2` and `proj_9_4` cfg flags.
+//!
 //!## Feature Flags
 //!
 //! - `pkg_config`: enables the use of `pkg-config` when linking against `libproj` —
@@ -57,9 +147,61 @@
 //! - `bundled_proj`: builds `libproj` from source bundled in the `proj-sys` crate.
 //!   Note that this feature requires Sqlite3 and `libtiff` to be present on your
 //!   system.
+//! - `bundled_proj_tiff`: as `bundled_proj`, but links `libtiff` statically, so the GeoTIFF grids
+//!   distributed by PROJ-data can be read without `libtiff` being installed where the application
+//!   runs. A static `libtiff` must be available through `pkg-config`.
+//! - `bundled_proj_static`: as `bundled_proj_tiff`, but also links SQLite statically, for fully
+//!   static binaries (e.g. for `x86_64-unknown-linux-musl`). See the
+//!   [`proj-sys`](https://docs.rs/proj-sys) documentation for cross-compilation.
+//! - `bundled_db`: embeds PROJ's database, `proj.db`, in the binary, so no PROJ resource files need
+//!   to be installed alongside it. The database is written to the temporary directory on first
+//!   use. It's taken from the `libproj` installation being linked against, or from the path in
+//!   the `PROJ_DB_PATH` environment variable if that's set at build time.
+//! - `bundled_grids`: embeds the grid files listed in the `PROJ_BUNDLED_GRIDS` environment variable
+//!   at build time (separated like `PATH`), so specific regional grids ship with the application.
+//!   See [`use_bundled_grids`](struct.ProjBuilder.html#method.use_bundled_grids).
 //! - `network`: exposes APIs which, when enabled, can fetch grid data from the internet to improve
 //!   projection accuracy. See [`enable_network`](struct.ProjBuilder.html#method.enable_network) for
 //!   details.
+//! - `network-native-tls`: as `network`, but uses the platform's native TLS stack for grid downloads
+//!   instead of `rustls`.
+//! - `network-object-store`: as `network`, but grids can also be read from S3, GCS, or Azure Blob
+//!   Storage.
+//! - `test-util`: as `network`, but grid responses can also be recorded and replayed, for
+//!   deterministic tests. See [`set_network_recording`](struct.ProjBuilder.html#method.set_network_recording).
+//! - `approx`: implements [`approx`](https://docs.rs/approx)'s `AbsDiffEq`, `RelativeEq` and
+//!   `UlpsEq` for [`ProjCoord`](proj/struct.ProjCoord.html), for comparing transformed
+//!   coordinates with a tolerance.
+//! - `capi`: exports a few functions with a C ABI, for creating and using transformations from C
+//!   and C++ applications which embed Rust. See [`capi`](capi/index.html).
+//! - `cli`: builds `rust-cs2cs`, a small `cs2cs`-like tool which reads `x y [z [t]]` coordinates
+//!   from standard input and writes the transformed coordinates, for debugging transformations
+//!   where the PROJ command line tools aren't installed. Its `info` mode describes a CRS and the
+//!   candidate operations to another CRS, as `projinfo` does. Run `rust-cs2cs --help` for its
+//!   usage.
+//! - `crs-definitions`: implements `TryFrom<crs_definitions::Def>` for [`Proj`](proj/struct.Proj.html),
+//!   so transformations can be created from the [`crs-definitions`](https://docs.rs/crs-definitions)
+//!   EPSG table.
+//! - `gdal`: adds [`from_gdal_spatial_ref`](proj/struct.Proj.html#method.from_gdal_spatial_ref) and
+//!   [`to_gdal_spatial_ref`](proj/struct.Proj.html#method.to_gdal_spatial_ref), for exchanging CRSs
+//!   with the [`gdal`](https://docs.rs/gdal) crate.
+//! - `ndarray`: adds [`convert_ndarray`](proj/struct.Proj.html#method.convert_ndarray), for
+//!   converting [`ndarray`](https://docs.rs/ndarray) matrices with a point per row.
+//! - `polars`: adds [`convert_chunked`](proj/struct.Proj.html#method.convert_chunked) and
+//!   [`convert_chunked_3d`](proj/struct.Proj.html#method.convert_chunked_3d), for reprojecting
+//!   [`polars`](https://docs.rs/polars) coordinate columns.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Proj`](proj/struct.Proj.html), using
+//!   [PROJJSON](https://proj.org/specifications/projjson.html). See [`projjson`](projjson/index.html).
+//! - `uom`: adds variants of the distance, area and accuracy getters that return
+//!   [`uom`](https://docs.rs/uom) quantities, such as
+//!   [`geodesic_distance_quantity`](proj/struct.Proj.html#method.geodesic_distance_quantity),
+//!   rather than bare `f64`s in metres.
+//! - `wkt`: adds [`convert_wkt`](proj/struct.Proj.html#method.convert_wkt), for converting
+//!   [`wkt`](https://docs.rs/wkt) geometries, and [`ewkt`](ewkt/index.html), for reprojecting
+//!   geometries with an embedded SRID.
+//! - `tracing`: emits [`tracing`](https://docs.rs/tracing) spans at the `DEBUG` level for transformation
+//!   creation, batch transforms (with the number of points), and grid requests (with their URL,
+//!   size, and duration).
 //!
 //! # Example
 //!
@@ -80,15 +222,156 @@
 //! assert_approx_eq!(result.x(), 1450880.29f64, 1.0e-2);
 //! assert_approx_eq!(result.y(), 1141263.01f64, 1.0e-2);
 //! ```
+//!
+//! Code being ported from Python can use [`Transformer`](struct.Transformer.html), which mirrors
+//! the naming and behaviour of [pyproj](https://pyproj4.github.io/pyproj/)'s `Transformer`.
+//!
+//! User input in degrees, minutes and seconds, such as `45d30'30"N`, can be converted to radians
+//! with [`dms_to_radians`](fn.dms_to_radians.html), and formatted with
+//! [`radians_to_dms`](fn.radians_to_dms.html).
+//!
+//! The [`tiles`](tiles/index.html) module converts between slippy map tile indices and their
+//! Web Mercator and longitude / latitude bounds.
+//!
+//! Android and iOS applications can use the [`mobile`](mobile/index.html) module to point PROJ at
+//! their sandbox's writable directory and bundled resources.
+//!
+//! Code that only needs to transform points can be written against the
+//! [`CoordTransform`](trait.CoordTransform.html) trait, which `Proj` implements, so that other
+//! transformation backends can be substituted.
 
+/// Enter a `tracing` span for the rest of the enclosing block, if the `tracing` feature is enabled
+macro_rules! trace_span {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($args)*).entered();
+    };
+}
+
+mod angles;
+mod axis_order;
+#[cfg(feature = "network")]
+mod bundle;
+#[cfg(feature = "bundled_db")]
+mod bundled_db;
+#[cfg(feature = "bundled_grids")]
+mod bundled_grids;
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "polars")]
+mod dataframe;
+#[cfg(any(feature = "bundled_db", feature = "bundled_grids"))]
+mod embedded;
+mod error_code;
+#[cfg_attr(docsrs, doc(cfg(feature = "wkt")))]
+#[cfg(feature = "wkt")]
+pub mod ewkt;
+mod file_finder;
+mod format;
+mod logging;
+#[cfg(feature = "ndarray")]
+mod matrix;
+pub mod mobile;
 #[cfg_attr(docsrs, feature(doc_cfg))]
 #[cfg(feature = "network")]
 mod network;
+#[cfg(feature = "network-object-store")]
+mod object_storage;
 mod proj;
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+pub mod projjson;
+#[cfg(feature = "test-util")]
+mod recording;
+#[cfg(feature = "gdal")]
+mod spatial_ref;
+mod suggest;
+pub mod tiles;
+mod topocentric;
+mod transform;
+mod transform_pair;
+mod transformer;
+#[cfg(feature = "uom")]
+mod units;
 
+pub use crate::angles::{dms_to_radians, radians_to_dms, to_degrees, to_radians};
+pub use crate::axis_order::detect_axis_order;
+pub use crate::axis_order::AxisOrder;
+pub use crate::axis_order::AxisOrderGuess;
+#[cfg_attr(docsrs, doc(cfg(feature = "bundled_grids")))]
+#[cfg(feature = "bundled_grids")]
+pub use crate::bundled_grids::bundled_grids;
+pub use crate::error_code::ErrorCategory;
+pub use crate::error_code::ErrorCode;
+pub use crate::format::CoordFormatter;
+pub use crate::logging::LogLevel;
+pub use crate::proj::enable_crs_cache;
+pub use crate::proj::enable_lossy_strings;
+pub use crate::proj::proj_version;
+pub use crate::proj::self_check;
+pub use crate::proj::AccuracyReport;
 pub use crate::proj::Area;
+pub use crate::proj::CacheRevalidation;
+pub use crate::proj::Candidates;
+pub use crate::proj::CrsExtentUse;
+pub use crate::proj::CrsToCrsOptions;
+pub use crate::proj::DatabaseInfo;
+pub use crate::proj::Explanation;
+pub use crate::proj::FailureValue;
+pub use crate::proj::FrozenPipeline;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::proj::GridCacheStats;
+pub use crate::proj::GridInfo;
+pub use crate::proj::GridTransformation;
 pub use crate::proj::Info;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::proj::NetworkBackend;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::proj::NetworkFailure;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::proj::NetworkStatus;
+pub use crate::proj::OperationComparison;
+pub use crate::proj::PipelineStep;
 pub use crate::proj::Proj;
 pub use crate::proj::ProjBuilder;
+pub use crate::proj::ProjCoord;
 pub use crate::proj::ProjError;
+pub use crate::proj::ProjVersion;
 pub use crate::proj::Projinfo;
+pub use crate::proj::SelfCheck;
+pub use crate::suggest::suggest_conformal_crs;
+pub use crate::suggest::suggest_equal_area_crs;
+pub use crate::topocentric::GlobalCoordinates;
+pub use crate::transform::CoordTransform;
+pub use crate::transform_pair::TransformPair;
+pub use crate::transformer::Transformer;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::network::CancellationToken;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::network::NetworkObserver;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use crate::network::RequestLimiter;
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+#[cfg(feature = "test-util")]
+pub use crate::recording::RecordingMode;
+
+/// The version of `reqwest` used for grid downloads, for use with
+/// [`set_network_client`](struct.ProjBuilder.html#method.set_network_client)
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub use reqwest;
+
+/// The version of `object_store` used to read grids from object stores, for use with
+/// [`set_network_object_store`](struct.ProjBuilder.html#method.set_network_object_store)
+#[cfg_attr(docsrs, doc(cfg(feature = "network-object-store")))]
+#[cfg(feature = "network-object-store")]
+pub use object_store;
@@ -0,0 +1,96 @@
+//! Routing PROJ's diagnostic messages to the `log` crate
+use crate::proj::_string;
+use libc::{c_char, c_int, c_void};
+use proj_sys::{
+    proj_context_create, proj_log_func, proj_log_level, PJ_CONTEXT, PJ_LOG_LEVEL,
+    PJ_LOG_LEVEL_PJ_LOG_DEBUG, PJ_LOG_LEVEL_PJ_LOG_ERROR, PJ_LOG_LEVEL_PJ_LOG_NONE,
+    PJ_LOG_LEVEL_PJ_LOG_TRACE,
+};
+use std::ptr;
+
+/// How much of PROJ's diagnostic output to log
+///
+/// Messages are logged with the `proj` target, at the corresponding `log` level.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LogLevel {
+    /// Don't log anything
+    None,
+    /// Log errors only. This is PROJ's default, unless the `PROJ_DEBUG` environment variable is set
+    Error,
+    /// Also log debugging information, such as the operations considered for a transformation
+    Debug,
+    /// Also log detailed tracing information, such as each grid lookup
+    Trace,
+}
+
+impl LogLevel {
+    fn pj_log_level(self) -> PJ_LOG_LEVEL {
+        match self {
+            LogLevel::None => PJ_LOG_LEVEL_PJ_LOG_NONE,
+            LogLevel::Error => PJ_LOG_LEVEL_PJ_LOG_ERROR,
+            LogLevel::Debug => PJ_LOG_LEVEL_PJ_LOG_DEBUG,
+            LogLevel::Trace => PJ_LOG_LEVEL_PJ_LOG_TRACE,
+        }
+    }
+}
+
+/// Called by libproj with each diagnostic message at or below the context's log level
+unsafe extern "C" fn log_message(_: *mut c_void, level: c_int, message: *const c_char) {
+    let level = match level as PJ_LOG_LEVEL {
+        PJ_LOG_LEVEL_PJ_LOG_ERROR => log::Level::Error,
+        PJ_LOG_LEVEL_PJ_LOG_DEBUG => log::Level::Debug,
+        _ => log::Level::Trace,
+    };
+    if let Ok(message) = _string(message) {
+        log::log!(target: "proj", level, "{}", message.trim_end());
+    }
+}
+
+/// Called by libproj with each diagnostic message while errors are being captured
+unsafe extern "C" fn capture_message(app_data: *mut c_void, level: c_int, message: *const c_char) {
+    let errors = &mut *(app_data as *mut Vec<String>);
+    if level as PJ_LOG_LEVEL == PJ_LOG_LEVEL_PJ_LOG_ERROR {
+        if let Ok(error) = _string(message) {
+            errors.push(error.trim_end().to_string());
+        }
+    }
+    log_message(ptr::null_mut(), level, message);
+}
+
+/// Call `f`, capturing the error messages PROJ logs meanwhile
+///
+/// The messages are logged as usual, too.
+pub(crate) fn capture_errors<T, F: FnOnce() -> T>(ctx: *mut PJ_CONTEXT, f: F) -> (T, Vec<String>) {
+    let mut errors: Vec<String> = vec![];
+    unsafe {
+        proj_log_func(
+            ctx,
+            &mut errors as *mut Vec<String> as *mut c_void,
+            Some(capture_message),
+        )
+    };
+    let result = f();
+    unsafe { proj_log_func(ctx, ptr::null_mut(), Some(log_message)) };
+    (result, errors)
+}
+
+/// Create a PROJ context which logs to the `log` crate, rather than to stderr
+///
+/// With the `bundled_db` feature, the context uses the embedded `proj.db`.
+pub(crate) fn create_context() -> *mut PJ_CONTEXT {
+    let ctx = adopt_context(unsafe { proj_context_create() });
+    #[cfg(feature = "bundled_db")]
+    crate::bundled_db::set_database(ctx);
+    ctx
+}
+
+/// Log a context created outside this crate to the `log` crate, as [`create_context`] does
+pub(crate) fn adopt_context(ctx: *mut PJ_CONTEXT) -> *mut PJ_CONTEXT {
+    unsafe { proj_log_func(ctx, ptr::null_mut(), Some(log_message)) };
+    ctx
+}
+
+/// Set how much of a context's diagnostic output is logged
+pub(crate) fn set_log_level(ctx: *mut PJ_CONTEXT, level: LogLevel) {
+    unsafe { proj_log_level(ctx, level.pj_log_level()) };
+}
@@ -0,0 +1,53 @@
+//! Conversion of coordinates held in [`ndarray`](https://docs.rs/ndarray) matrices
+use crate::proj::{Proj, ProjError};
+use ndarray::Array2;
+
+impl Proj {
+    /// Convert a matrix of coordinates in place, one point per row
+    ///
+    /// The matrix must have two columns, x and y, or three, x, y and z. Row-major (C order)
+    /// matrices are passed to PROJ as they are, with each axis read `ncols` values apart, and
+    /// column-major (Fortran order) matrices are converted as columns, as by
+    /// [`convert_columns`](#method.convert_columns), so neither is copied. Any other shape is
+    /// reported as an [`ArrayShape`](enum.ProjError.html#variant.ArrayShape) error, and
+    /// matrices which aren't contiguous, e.g. slices of larger matrices, as an
+    /// [`ArrayLayout`](enum.ProjError.html#variant.ArrayLayout) error.
+    ///
+    /// As with [`convert_columns`](#method.convert_columns), a point which can't be converted
+    /// doesn't stop the conversion: its coordinates are set to infinity.
+    ///
+    /// ```rust
+    /// use ndarray::array;
+    /// use proj::Proj;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+    /// let mut points = array![
+    ///     [4760096.421921, 3744293.729449],
+    ///     [4760197.421921, 3744394.729449]
+    /// ];
+    /// ft_to_m.convert_ndarray(&mut points).unwrap();
+    /// assert_approx_eq!(points[[0, 0]], 1450880.2910605003f64);
+    /// assert_approx_eq!(points[[1, 1]], 1141293.7960220212f64);
+    /// ```
+    pub fn convert_ndarray(&self, coords: &mut Array2<f64>) -> Result<(), ProjError> {
+        let (rows, columns) = coords.dim();
+        if !(2..=3).contains(&columns) {
+            return Err(ProjError::ArrayShape(columns));
+        }
+        let row_major = coords.is_standard_layout();
+        let column_major = coords.t().is_standard_layout();
+        let values = coords
+            .as_slice_memory_order_mut()
+            .ok_or(ProjError::ArrayLayout)?;
+        if row_major {
+            self.convert_interleaved(values, columns)
+        } else if column_major {
+            let (x, rest) = values.split_at_mut(rows);
+            let (y, z) = rest.split_at_mut(rows);
+            let z = Some(z).filter(|_| columns == 3);
+            self.convert_columns(x, y, z)
+        } else {
+            Err(ProjError::ArrayLayout)
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Locating an application's directories on Android and iOS, where PROJ's defaults don't work
+//!
+//! Mobile applications run in a sandbox: on Android, PROJ's default user-writable directory
+//! (`$HOME/.local/share/proj`, or `/tmp/proj`) isn't writable, and on both platforms grids
+//! shipped with the application aren't in any of PROJ's search paths.
+//! [`set_app_directories`](../struct.ProjBuilder.html#method.set_app_directories) points PROJ at
+//! the directories found by [`AppDirectories::locate`].
+//!
+//! PROJ downloads grids into its user-writable directory, which can only be changed with the
+//! `PROJ_USER_WRITABLE_DIRECTORY` environment variable. The application should set it to the
+//! writable directory at startup, before any threads are started, as changing the environment
+//! races with other threads reading it.
+//!
+//! ```no_run
+//! use proj::mobile::AppDirectories;
+//! use proj::ProjBuilder;
+//!
+//! let dirs = AppDirectories::locate();
+//! if let Some(dirs) = &dirs {
+//!     std::env::set_var("PROJ_USER_WRITABLE_DIRECTORY", &dirs.writable);
+//! }
+//! // …
+//! let mut builder = ProjBuilder::new();
+//! if let Some(dirs) = &dirs {
+//!     builder.set_app_directories(dirs).unwrap();
+//! }
+//! let proj = builder.proj_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+//! ```
+use std::path::PathBuf;
+
+/// The directories PROJ should use inside an application's sandbox
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppDirectories {
+    /// A directory the application can write to, used for the grid cache and downloaded grids
+    pub writable: PathBuf,
+    /// A directory of resource files (grids, init files) shipped with the application
+    pub resources: Option<PathBuf>,
+}
+
+impl AppDirectories {
+    pub fn new<P: Into<PathBuf>>(writable: P, resources: Option<P>) -> Self {
+        AppDirectories {
+            writable: writable.into(),
+            resources: resources.map(Into::into),
+        }
+    }
+
+    /// Locate the current application's directories
+    ///
+    /// - On iOS, the writable directory is `Library/Application Support/proj` in the
+    ///   application's home directory, and the resources are the `proj` directory of the app
+    ///   bundle, if there is one.
+    /// - On Android, the writable directory is `proj` in the application's internal files
+    ///   directory (as returned by `Context.getFilesDir()`), found using the process's package
+    ///   name. Assets packed in the APK aren't files, so there are no resources: copy them to a
+    ///   directory first, or serve them with a
+    ///   [file finder](../struct.ProjBuilder.html#method.set_file_finder).
+    ///
+    /// Returns `None` on other platforms, or if the directories can't be determined.
+    pub fn locate() -> Option<AppDirectories> {
+        locate()
+    }
+}
+
+#[cfg(target_os = "ios")]
+fn locate() -> Option<AppDirectories> {
+    let home = PathBuf::from(std::env::var_os("HOME")?);
+    let bundle = std::env::current_exe().ok()?.parent()?.join("proj");
+    Some(AppDirectories {
+        writable: home.join("Library/Application Support/proj"),
+        resources: Some(bundle).filter(|bundle| bundle.is_dir()),
+    })
+}
+
+#[cfg(target_os = "android")]
+fn locate() -> Option<AppDirectories> {
+    // An application's main process is named after its package; other processes are named
+    // `<package>:<process>`
+    let cmdline = std::fs::read("/proc/self/cmdline").ok()?;
+    let name = cmdline.split(|byte| *byte == 0).next()?;
+    let package = std::str::from_utf8(name).ok()?.split(':').next()?;
+    if package.is_empty() || package.contains('/') {
+        return None;
+    }
+    Some(AppDirectories {
+        writable: PathBuf::from("/data/data").join(package).join("files/proj"),
+        resources: None,
+    })
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+fn locate() -> Option<AppDirectories> {
+    None
+}
@@ -8,10 +8,27 @@
 use proj_sys::{proj_context_set_network_callbacks, PJ_CONTEXT, PROJ_NETWORK_HANDLE};
 
 use reqwest::blocking::{Client, RequestBuilder, Response};
-use reqwest::Method;
+use reqwest::header::{
+    HeaderMap, HeaderValue, CONTENT_RANGE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE,
+    LAST_MODIFIED, RANGE, USER_AGENT,
+};
+use reqwest::{Certificate, Method, Proxy, StatusCode};
+use sha2::{Digest, Sha256};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::ffi::CString;
+use std::io::Read;
 use std::os::raw::c_ulonglong;
+use std::path::Path;
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Instant;
+
+#[cfg(feature = "network-object-store")]
+use crate::object_storage::ObjectStoreSource;
+#[cfg(feature = "test-util")]
+use crate::recording::Recording;
 
 use crate::proj::{ProjError, _string};
 use libc::c_char;
@@ -24,10 +41,570 @@ const MAX_RETRIES: u8 = 8;
 // S3 sometimes sends these in place of actual client errors, so retry instead of erroring
 const RETRY_CODES: [u16; 4] = [429, 500, 502, 504];
 
+/// How grid requests should be routed
+pub(crate) enum ProxySetting {
+    /// Use the proxies given by the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables
+    System,
+    /// Connect directly, ignoring any proxy environment variables
+    Disabled,
+    /// Send all requests through the given proxy
+    Explicit(Proxy),
+}
+
+/// Receives progress reports from grid downloads
+///
+/// libproj reads grids in chunks: each grid file is opened, ranges of it are read as they are
+/// required, and it is then closed. All methods have empty default implementations, so only
+/// the events of interest need to be handled.
+///
+/// ```rust
+/// use proj::{NetworkObserver, ProjBuilder};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// struct ByteCounter(Arc<AtomicUsize>);
+///
+/// impl NetworkObserver for ByteCounter {
+///     fn bytes_received(&self, _url: &str, bytes: usize) {
+///         self.0.fetch_add(bytes, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let total = Arc::new(AtomicUsize::new(0));
+/// let mut builder = ProjBuilder::new();
+/// builder.set_network_observer(ByteCounter(total.clone()));
+/// ```
+pub trait NetworkObserver {
+    /// A grid file is about to be opened
+    fn download_started(&self, _url: &str) {}
+    /// A range of a grid file has been downloaded
+    fn bytes_received(&self, _url: &str, _bytes: usize) {}
+    /// A request for a grid file returned HTTP status `status`, and is being retried
+    fn retrying(&self, _url: &str, _status: u16, _attempt: u8) {}
+    /// A request for a grid file failed
+    fn download_failed(&self, _url: &str, _error: &ProjError) {}
+    /// libproj has finished reading from a grid file
+    fn download_finished(&self, _url: &str) {}
+    /// [`Proj::prefetch_grids`](struct.Proj.html#method.prefetch_grids) has downloaded (or found
+    /// an up-to-date copy of) `grid`, the `completed`th of `total` grids
+    fn grid_prefetched(&self, _grid: &str, _completed: usize, _total: usize) {}
+    /// A complete grid has been downloaded into the user-writable directory, by
+    /// [`ProjBuilder::download_grid`](struct.ProjBuilder.html#method.download_grid) or
+    /// [`Proj::prefetch_grids`](struct.Proj.html#method.prefetch_grids). Transformations created
+    /// before it was available can use it after [`Proj::rescan_grids`](struct.Proj.html#method.rescan_grids)
+    fn grid_downloaded(&self, _grid: &str) {}
+}
+
+/// Aborts the grid downloads of a transformation: see
+/// [`Proj::with_cancellation`](struct.Proj.html#method.with_cancellation)
+///
+/// Clones share their cancellation, so a token can be cancelled from another thread, e.g. when
+/// the web request a transformation serves times out.
+///
+/// ```rust
+/// use proj::CancellationToken;
+/// use std::time::Duration;
+///
+/// let token = CancellationToken::with_timeout(Duration::from_secs(5));
+/// assert!(!token.is_cancelled());
+/// token.clone().cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// A token which is only cancelled by [`cancel`](#method.cancel)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token which is also cancelled once `timeout` has elapsed
+    pub fn with_timeout(timeout: time::Duration) -> Self {
+        Self {
+            deadline: Instant::now().checked_add(timeout),
+            ..Self::default()
+        }
+    }
+
+    /// Cancel the token and its clones
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the token has been cancelled, or its timeout has elapsed
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// The time left before the timeout elapses, if there is one
+    fn remaining(&self) -> Option<time::Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// Limits the rate and concurrency of grid download requests
+///
+/// Clones share their limits, so a single limiter can be passed to the
+/// [`ProjBuilder`](struct.ProjBuilder.html)s of many threads, e.g. to avoid overloading a small
+/// mirror during a batch job.
+///
+/// ```rust
+/// use proj::{ProjBuilder, RequestLimiter};
+///
+/// // At most 4 requests in flight, and 10 requests per second on average
+/// let limiter = RequestLimiter::new(4).with_rate(10.0, 10);
+/// let mut builder = ProjBuilder::new();
+/// builder.set_network_limiter(limiter.clone());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RequestLimiter {
+    inner: Arc<LimiterState>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    max_concurrent: usize,
+    in_flight: Mutex<usize>,
+    released: Condvar,
+    bucket: Option<Mutex<TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    per_second: f64,
+    capacity: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    /// Take a token, returning how long to wait before the request may be sent
+    fn take(&mut self) -> time::Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.capacity);
+        self.updated = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            time::Duration::from_secs(0)
+        } else {
+            time::Duration::from_secs_f64(-self.tokens / self.per_second)
+        }
+    }
+}
+
+impl RequestLimiter {
+    /// Allow at most `max_concurrent` requests to be in flight at once (minimum 1)
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            inner: Arc::new(LimiterState {
+                max_concurrent: max_concurrent.max(1),
+                in_flight: Mutex::new(0),
+                released: Condvar::new(),
+                bucket: None,
+            }),
+        }
+    }
+
+    /// Additionally allow at most `per_second` requests per second, with bursts of up to `burst`
+    ///
+    /// Requests exceeding the rate are delayed rather than rejected.
+    ///
+    /// # Panics
+    /// Panics if `per_second` isn't a positive, finite number.
+    pub fn with_rate(self, per_second: f64, burst: u32) -> Self {
+        assert!(
+            per_second > 0.0 && per_second.is_finite(),
+            "the request rate must be positive and finite, not {}",
+            per_second
+        );
+        let capacity = f64::from(burst.max(1));
+        let bucket = TokenBucket {
+            per_second,
+            capacity,
+            tokens: capacity,
+            updated: Instant::now(),
+        };
+        Self {
+            inner: Arc::new(LimiterState {
+                max_concurrent: self.inner.max_concurrent,
+                in_flight: Mutex::new(0),
+                released: Condvar::new(),
+                bucket: Some(Mutex::new(bucket)),
+            }),
+        }
+    }
+
+    /// Wait for a concurrency slot, which is released when the permit is dropped
+    fn acquire(&self) -> Permit<'_> {
+        let state = &self.inner;
+        let mut in_flight = state.in_flight.lock().unwrap();
+        while *in_flight >= state.max_concurrent {
+            in_flight = state.released.wait(in_flight).unwrap();
+        }
+        *in_flight += 1;
+        Permit { state }
+    }
+
+    /// Wait until the rate limit allows another request to be sent
+    fn throttle(&self) {
+        let wait = match &self.inner.bucket {
+            Some(bucket) => bucket.lock().unwrap().take(),
+            None => return,
+        };
+        thread::sleep(wait);
+    }
+}
+
+/// A concurrency slot, held while a request is in flight
+struct Permit<'a> {
+    state: &'a LimiterState,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        *self.state.in_flight.lock().unwrap() -= 1;
+        self.state.released.notify_one();
+    }
+}
+
+/// Returns additional headers for a request to the given URL
+pub(crate) type HeaderCallback = Box<dyn Fn(&str) -> HeaderMap>;
+
+/// A range of a grid file, along with the response headers PROJ will query
+///
+/// The first chunk of each grid file is kept so that it can be revalidated using a conditional
+/// request: when its cache TTL expires, PROJ re-opens each grid file to compare its `ETag` and
+/// `Last-Modified` headers with those it has stored. Answering from here when the server returns
+/// `304 Not Modified` avoids downloading the chunk again.
+pub(crate) struct Chunk {
+    pub(crate) range: String,
+    pub(crate) headers: HeaderMap,
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl Chunk {
+    /// Make `request` conditional on the chunk having changed
+    fn conditional(&self, request: RequestBuilder) -> RequestBuilder {
+        let mut request = request;
+        if let Some(etag) = self.headers.get(ETAG) {
+            request = request.header(IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(modified) = self.headers.get(LAST_MODIFIED) {
+            request = request.header(IF_MODIFIED_SINCE, modified.clone());
+        }
+        request
+    }
+}
+
+/// Per-context settings for the grid download client
+///
+/// A pointer to this struct is passed to libproj as the user data of the network callbacks,
+/// so each context can be configured independently. It must outlive the context.
+pub(crate) struct NetworkConfig {
+    /// A user-supplied client, which takes precedence over all other settings
+    pub(crate) client: Option<Client>,
+    pub(crate) proxy: ProxySetting,
+    /// Trusted in addition to the TLS backend's default roots
+    pub(crate) root_certificates: Vec<Certificate>,
+    /// Identifies the application in the `Client` and `User-Agent` headers of every request
+    pub(crate) user_agent: HeaderValue,
+    /// Sent with every request
+    pub(crate) headers: HeaderMap,
+    /// Called before every request, so that e.g. short-lived tokens can be refreshed
+    pub(crate) header_callback: Option<HeaderCallback>,
+    pub(crate) observer: Option<Box<dyn NetworkObserver>>,
+    /// Keyed by URL. Callbacks only have shared access to the config, hence the `RefCell`
+    revalidation_cache: RefCell<HashMap<String, Chunk>>,
+    /// Read grids from here rather than over HTTP
+    #[cfg(feature = "network-object-store")]
+    pub(crate) object_store: Option<Arc<ObjectStoreSource>>,
+    pub(crate) limiter: Option<RequestLimiter>,
+    /// The minimum number of bytes to request when reading a range, 0 to disable readahead
+    pub(crate) readahead: usize,
+    #[cfg(feature = "test-util")]
+    pub(crate) recording: Option<Recording>,
+    /// Check grids against their published checksums after downloading them
+    pub(crate) verify_downloads: bool,
+    /// Published SHA-256 checksums, keyed by grid file name. Fetched when first required
+    checksums: RefCell<Option<HashMap<String, String>>>,
+    /// Aborts requests made during `Proj::with_cancellation`
+    cancellation: RefCell<Option<CancellationToken>>,
+    /// Whether a request has been aborted by the cancellation token
+    cancelled: Cell<bool>,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            client: None,
+            proxy: ProxySetting::System,
+            root_certificates: vec![],
+            user_agent: HeaderValue::from_static(CLIENT),
+            headers: HeaderMap::new(),
+            header_callback: None,
+            observer: None,
+            revalidation_cache: RefCell::new(HashMap::new()),
+            #[cfg(feature = "network-object-store")]
+            object_store: None,
+            limiter: None,
+            readahead: 0,
+            #[cfg(feature = "test-util")]
+            recording: None,
+            verify_downloads: false,
+            checksums: RefCell::new(None),
+            cancellation: RefCell::new(None),
+            cancelled: Cell::new(false),
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Return the user-supplied client, or build one for grid requests using the current settings
+    fn client(&self) -> Result<Client, ProjError> {
+        if let Some(client) = &self.client {
+            // Clients are reference-counted, so this reuses its connection pool
+            return Ok(client.clone());
+        }
+        let mut builder = match &self.proxy {
+            ProxySetting::System => Client::builder(),
+            ProxySetting::Disabled => Client::builder().no_proxy(),
+            ProxySetting::Explicit(proxy) => Client::builder().proxy(proxy.clone()),
+        };
+        #[cfg(feature = "network-native-tls")]
+        {
+            builder = builder.use_native_tls();
+        }
+        for cert in &self.root_certificates {
+            builder = builder.add_root_certificate(cert.clone());
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Report an event to the observer, if there is one
+    pub(crate) fn observe<F: FnOnce(&dyn NetworkObserver)>(&self, event: F) {
+        if let Some(observer) = &self.observer {
+            event(observer.as_ref())
+        }
+    }
+
+    /// Save a response, if recording
+    #[cfg(feature = "test-util")]
+    fn record(&self, url: &str, chunk: &Chunk) -> Result<(), ProjError> {
+        match &self.recording {
+            Some(recording) => recording.save(url, chunk),
+            None => Ok(()),
+        }
+    }
+
+    /// The recording to answer requests from, if replaying
+    #[cfg(feature = "test-util")]
+    fn replay(&self) -> Option<&Recording> {
+        self.recording
+            .as_ref()
+            .filter(|recording| recording.replaying())
+    }
+
+    /// Abort requests once `token` is cancelled, or stop aborting them if it's `None`
+    pub(crate) fn set_cancellation(&self, token: Option<CancellationToken>) {
+        *self.cancellation.borrow_mut() = token;
+    }
+
+    /// Whether a request has been aborted since this was last called
+    pub(crate) fn take_cancelled(&self) -> bool {
+        self.cancelled.replace(false)
+    }
+
+    /// Fail with a [`Cancelled`](enum.ProjError.html#variant.Cancelled) error if the cancellation
+    /// token, if any, has been cancelled
+    fn check_cancelled(&self) -> Result<(), ProjError> {
+        if let Some(token) = self.cancellation.borrow().as_ref() {
+            if token.is_cancelled() {
+                self.cancelled.set(true);
+                return Err(ProjError::Cancelled);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait until the limiter, if any, allows a request to be sent
+    ///
+    /// The returned permit must be held until the response body has been read.
+    fn limit(&self) -> Option<Permit<'_>> {
+        self.limiter.as_ref().map(|limiter| {
+            let permit = limiter.acquire();
+            limiter.throttle();
+            permit
+        })
+    }
+
+    /// Wait until the limiter, if any, allows a request to be retried
+    fn throttle(&self) {
+        if let Some(limiter) = &self.limiter {
+            limiter.throttle();
+        }
+    }
+
+    /// Take the cached first chunk of a grid file, if it covers `range`
+    fn take_cached_chunk(&self, url: &str, range: &str) -> Option<Chunk> {
+        self.revalidation_cache
+            .borrow_mut()
+            .remove(url)
+            .filter(|chunk| chunk.range == range)
+    }
+
+    /// Forget all cached first chunks, e.g. when PROJ's grid cache is cleared
+    pub(crate) fn clear_cached_chunks(&self) {
+        self.revalidation_cache.borrow_mut().clear();
+    }
+
+    /// Cache the first chunk of a grid file, if the server supports conditional requests for it
+    fn cache_chunk(&self, url: &str, chunk: Chunk) {
+        if chunk.headers.contains_key(ETAG) || chunk.headers.contains_key(LAST_MODIFIED) {
+            self.revalidation_cache
+                .borrow_mut()
+                .insert(url.to_string(), chunk);
+        }
+    }
+
+    /// The SHA-256 checksum published for a grid in the endpoint's `files.geojson`, if it's listed
+    pub(crate) fn published_checksum(
+        &self,
+        endpoint: &str,
+        name: &str,
+    ) -> Result<Option<String>, ProjError> {
+        let mut checksums = self.checksums.borrow_mut();
+        if checksums.is_none() {
+            let url = format!("{}/files.geojson", endpoint.trim_end_matches('/'));
+            let request = self.with_headers(self.client()?.get(&url), &url);
+            let _permit = self.limit();
+            let res = request.send()?.error_for_status()?;
+            let manifest: serde_json::Value = serde_json::from_slice(&res.bytes()?)?;
+            *checksums = Some(parse_manifest(&manifest));
+        }
+        Ok(checksums.as_ref().and_then(|c| c.get(name).cloned()))
+    }
+
+    /// Send a request to an endpoint, to check that it's reachable
+    ///
+    /// Any response other than a server error counts, since endpoints needn't serve their root.
+    pub(crate) fn ping(&self, endpoint: &str) -> Result<(), ProjError> {
+        let request = self.with_headers(self.client()?.head(endpoint), endpoint);
+        let _permit = self.limit();
+        let res = request.send()?;
+        if res.status().is_server_error() {
+            return Err(ProjError::Endpoint(endpoint.to_string(), res.status()));
+        }
+        Ok(())
+    }
+
+    /// The identification headers, the static headers, and any headers returned by the callback
+    /// for a request to `url`. Later headers replace earlier ones with the same name, so each is
+    /// sent once
+    pub(crate) fn request_headers(&self, url: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("Client", self.user_agent.clone());
+        headers.insert(USER_AGENT, self.user_agent.clone());
+        headers.extend(self.headers.clone());
+        if let Some(callback) = &self.header_callback {
+            headers.extend(callback(url));
+        }
+        headers
+    }
+
+    /// Add the [request headers](#method.request_headers) to a request, replacing any it already
+    /// has with the same names
+    ///
+    /// If the cancellation token has a timeout, the request times out with it.
+    pub(crate) fn with_headers(&self, request: RequestBuilder, url: &str) -> RequestBuilder {
+        let mut request = request.headers(self.request_headers(url));
+        let remaining = self
+            .cancellation
+            .borrow()
+            .as_ref()
+            .and_then(CancellationToken::remaining);
+        if let Some(remaining) = remaining {
+            request = request.timeout(remaining);
+        }
+        request
+    }
+}
+
+/// Extract grid names and checksums from a CDN's `files.geojson`
+fn parse_manifest(manifest: &serde_json::Value) -> HashMap<String, String> {
+    manifest["features"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|feature| {
+            let properties = &feature["properties"];
+            let name = properties["name"].as_str()?;
+            let checksum = properties["sha256sum"].as_str()?;
+            Some((name.to_string(), checksum.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// The hex-encoded SHA-256 checksum of a file
+pub(crate) fn sha256_file(path: &Path) -> Result<String, ProjError> {
+    let read_error = |e| ProjError::GridRead(path.display().to_string(), e);
+    let mut file = std::fs::File::open(path).map_err(read_error)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(read_error)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Split a PEM bundle into its individual certificates
+///
+/// Not every TLS backend will read more than the first certificate from a PEM block
+pub(crate) fn parse_pem_bundle(bundle: &[u8]) -> Result<Vec<Certificate>, ProjError> {
+    const END: &str = "-----END CERTIFICATE-----";
+    let bundle = std::str::from_utf8(bundle)?;
+    let certs = bundle
+        .split_inclusive(END)
+        .filter(|pem| pem.contains(END))
+        .map(|pem| Certificate::from_pem(pem.trim().as_bytes()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ProjError::Certificate)?;
+    if certs.is_empty() {
+        return Err(ProjError::Certificate);
+    }
+    // rustls only decodes certificates when a client is built, so check them up front
+    certs
+        .iter()
+        .fold(Client::builder(), |builder, cert| {
+            builder.add_root_certificate(cert.clone())
+        })
+        .build()
+        .map_err(|_| ProjError::Certificate)?;
+    Ok(certs)
+}
+
+/// Where the ranges of an open grid file are read from
+// Only ever stored in a heap-allocated `HandleData`, so the size difference doesn't matter
+#[allow(clippy::large_enum_variant)]
+enum Source {
+    Http(RequestBuilder),
+    #[cfg(feature = "network-object-store")]
+    ObjectStore(Arc<ObjectStoreSource>),
+    /// Recorded responses, which are looked up in the config
+    #[cfg(feature = "test-util")]
+    Replay,
+}
+
 /// This struct is cast to `c_void`, then to `PROJ_NETWORK_HANDLE` so it can be passed around
 #[no_mangle]
 struct HandleData {
-    request: reqwest::blocking::RequestBuilder,
+    url: String,
+    source: Source,
     headers: reqwest::header::HeaderMap,
     // this raw pointer is returned to libproj but never returned from libproj,
     // so a copy of the pointer (raw pointers are Copy) is stored here, so it can be
@@ -35,20 +612,32 @@ struct HandleData {
     // Note to future self: are you 100% sure that the pointer is never read again
     // after network_close returns?
     hptr: Option<*const c_char>,
+    /// Bytes read ahead of the last range request, and the offset they start at
+    readahead: Option<(usize, Vec<u8>)>,
 }
 
 impl HandleData {
     fn new(
-        request: reqwest::blocking::RequestBuilder,
+        url: String,
+        source: Source,
         headers: reqwest::header::HeaderMap,
         hptr: Option<*const c_char>,
     ) -> Self {
         Self {
-            request,
+            url,
+            source,
             headers,
             hptr,
+            readahead: None,
         }
     }
+
+    /// Take `size` bytes at `offset` from the readahead buffer, if it contains them
+    fn read_ahead(&self, offset: usize, size: usize) -> Option<&[u8]> {
+        let (start, bytes) = self.readahead.as_ref()?;
+        let from = offset.checked_sub(*start)?;
+        bytes.get(from..from + size)
+    }
 }
 
 /// Return an exponential wait time based on the number of retries
@@ -62,7 +651,11 @@ fn get_wait_time_exp(retrycount: i32) -> u64 {
 }
 
 /// Process CDN response: handle retries in case of server error, or early return for client errors
-fn error_handler<'a>(res: &'a mut Response, rb: RequestBuilder) -> Result<&'a Response, ProjError> {
+fn error_handler<'a>(
+    res: &'a mut Response,
+    rb: RequestBuilder,
+    config: &NetworkConfig,
+) -> Result<&'a Response, ProjError> {
     let mut status = res.status().as_u16();
     let mut retries = 0;
     // Check whether something went wrong on the server, or if it's an S3 retry code
@@ -72,11 +665,13 @@ fn error_handler<'a>(res: &'a mut Response, rb: RequestBuilder) -> Result<&'a Re
             && retries <= MAX_RETRIES
         {
             retries += 1;
+            config.observe(|o| o.retrying(res.url().as_str(), status, retries));
             let wait = time::Duration::from_millis(get_wait_time_exp(retries as i32));
             thread::sleep(wait);
+            config.check_cancelled()?;
+            config.throttle();
             let retry = rb.try_clone().ok_or(ProjError::RequestCloneError)?;
-            let with_range = retry.header("Client", CLIENT);
-            *res = with_range.send()?;
+            *res = retry.send()?;
             status = res.status().as_u16();
         }
     // Not a timeout or known S3 retry code: bail out
@@ -134,9 +729,11 @@ pub(crate) unsafe extern "C" fn network_open(
     ) {
         Ok(res) => res,
         Err(e) => {
-            let err_string = e.to_string();
-            out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
-            out_error_string.add(err_string.len()).write(0);
+            let config = &*(ud as *const NetworkConfig);
+            // A request which timed out with the cancellation token was cancelled
+            let e = config.check_cancelled().err().unwrap_or(e);
+            config.observe(|o| o.download_failed(&_string(url).unwrap_or_default(), &e));
+            write_error_string(&e.to_string(), error_string_max_size, out_error_string);
             ptr::null_mut() as *mut PROJ_NETWORK_HANDLE
         }
     }
@@ -150,61 +747,251 @@ fn _network_open(
     size_to_read: usize,
     buffer: *mut c_void,
     out_size_read: *mut usize,
-    _: usize,
+    error_string_max_size: usize,
     out_error_string: *mut c_char,
-    _: *mut c_void,
+    ud: *mut c_void,
 ) -> Result<*mut PROJ_NETWORK_HANDLE, ProjError> {
+    let config = unsafe { &*(ud as *const NetworkConfig) };
     let url = _string(url)?;
+    config.check_cancelled()?;
+    config.observe(|o| o.download_started(&url));
     // - 1 is used because the HTTP convention is to use inclusive start and end offsets
     let end = offset as usize + size_to_read - 1;
     // RANGE header definition is "bytes=x-y"
     let hvalue = format!("bytes={}-{}", offset, end);
-    // Create a new client that can be reused for subsequent queries
-    let clt = Client::builder().build()?;
-    let req = clt.request(Method::GET, &url);
-    // this performs the initial byte read, presumably as an error check
-    let initial = req.try_clone().ok_or(ProjError::RequestCloneError)?;
-    let with_headers = initial.header("Range", &hvalue).header("Client", CLIENT);
-    let mut res = with_headers.send()?;
-    let eh_rb = req
-        .try_clone()
-        .ok_or(ProjError::RequestCloneError)?
-        .header("Range", &hvalue);
-    // hand the response off to the error-handler, continue on success
-    error_handler(&mut res, eh_rb)?;
-    // Write the initial read length value into the pointer
-    let contentlength = res.content_length().ok_or(ProjError::ContentLength)? as usize;
+    let (source, chunk) = traced(
+        &url,
+        offset as usize,
+        || open_source(config, &url, offset as usize, size_to_read, hvalue),
+        |(_, chunk)| chunk.bytes.len(),
+    )?;
+    #[cfg(feature = "test-util")]
+    config.record(&url, &chunk)?;
+    // Report the number of bytes copied into the buffer, which may be fewer than were received
+    let contentlength = chunk.bytes.len().min(size_to_read);
     unsafe { out_size_read.write(contentlength) };
-    let headers = res.headers().clone();
+    let headers = chunk.headers.clone();
     // Copy the downloaded bytes into the buffer so it can be passed around
     unsafe {
-        &res.bytes()?
+        chunk
+            .bytes
             .as_ptr()
-            .copy_to_nonoverlapping(buffer as *mut u8, contentlength.min(size_to_read))
+            .copy_to_nonoverlapping(buffer as *mut u8, contentlength)
     };
-    // Store req into the handle so new ranges can be queried
-    let hd = HandleData::new(req, headers, None);
+    if matches!(source, Source::Http(_)) {
+        config.cache_chunk(&url, chunk);
+    }
+    // Store the source into the handle so new ranges can be queried
+    let hd = HandleData::new(url, source, headers, None);
     // heap-allocate the struct and cast it to a void pointer so it can be passed around to PROJ
     let hd_boxed = Box::new(hd);
     let void: *mut c_void = Box::into_raw(hd_boxed) as *mut c_void;
     let opaque: *mut PROJ_NETWORK_HANDLE = void as *mut PROJ_NETWORK_HANDLE;
     // If everything's OK, set the error string to empty
-    let err_string = "";
-    unsafe {
-        out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
-        out_error_string.add(err_string.len()).write(0);
-    }
+    unsafe { write_error_string("", error_string_max_size, out_error_string) };
     Ok(opaque)
 }
 
+/// Read the first range of a grid file from wherever grids are configured to be read from
+#[cfg_attr(not(feature = "network-object-store"), allow(unused_variables))]
+fn open_source(
+    config: &NetworkConfig,
+    url: &str,
+    offset: usize,
+    size: usize,
+    range: String,
+) -> Result<(Source, Chunk), ProjError> {
+    #[cfg(feature = "test-util")]
+    {
+        if let Some(recording) = config.replay() {
+            return Ok((Source::Replay, recording.load(url, &range)?));
+        }
+    }
+    #[cfg(feature = "network-object-store")]
+    {
+        if let Some(store) = &config.object_store {
+            let chunk = {
+                let _permit = config.limit();
+                store.read(url, offset, size)?
+            };
+            config.observe(|o| o.bytes_received(url, chunk.bytes.len()));
+            return Ok((Source::ObjectStore(store.clone()), chunk));
+        }
+    }
+    http_open(config, url, offset, range)
+}
+
+/// A `Range` header requesting `range`, e.g. `"bytes=0-16383"`, which replaces any set by the user
+fn range_header(range: &str) -> Result<HeaderMap, ProjError> {
+    let value =
+        HeaderValue::from_str(range).map_err(|_| ProjError::RequestHeader(RANGE.to_string()))?;
+    let mut headers = HeaderMap::new();
+    headers.insert(RANGE, value);
+    Ok(headers)
+}
+
+/// Request the first range of a grid file over HTTP
+///
+/// Returns the request, so that subsequent ranges can be requested using the same client
+fn http_open(
+    config: &NetworkConfig,
+    url: &str,
+    offset: usize,
+    range: String,
+) -> Result<(Source, Chunk), ProjError> {
+    // Create a new client that can be reused for subsequent queries
+    let clt = config.client()?;
+    let req = config.with_headers(clt.request(Method::GET, url), url);
+    // this performs the initial byte read, presumably as an error check
+    let initial = req.try_clone().ok_or(ProjError::RequestCloneError)?;
+    let mut with_headers = initial.headers(range_header(&range)?);
+    // If this chunk has been downloaded before, only download it again if it has changed
+    let cached = config.take_cached_chunk(url, &range);
+    if let Some(chunk) = &cached {
+        with_headers = chunk.conditional(with_headers);
+    }
+    let _permit = config.limit();
+    let mut res = with_headers.send()?;
+    let eh_rb = req
+        .try_clone()
+        .ok_or(ProjError::RequestCloneError)?
+        .headers(range_header(&range)?);
+    let chunk = match cached {
+        Some(chunk) if res.status() == StatusCode::NOT_MODIFIED => chunk,
+        _ => {
+            // hand the response off to the error-handler, continue on success
+            error_handler(&mut res, eh_rb, config)?;
+            check_content_range(url, res.status(), res.headers(), offset)?;
+            let headers = res.headers().clone();
+            let chunk = Chunk {
+                range,
+                headers,
+                bytes: read_body(config, res, &req, offset)?,
+            };
+            config.observe(|o| o.bytes_received(url, chunk.bytes.len()));
+            chunk
+        }
+    };
+    Ok((Source::Http(req), chunk))
+}
+
+/// Request a subsequent range of a grid file over HTTP
+fn http_read_range(
+    config: &NetworkConfig,
+    request: &RequestBuilder,
+    url: &str,
+    offset: usize,
+    range: String,
+) -> Result<Chunk, ProjError> {
+    // Headers are refreshed for every range request, in case the callback's output has changed
+    let req = config.with_headers(
+        request.try_clone().ok_or(ProjError::RequestCloneError)?,
+        url,
+    );
+    let initial = req.try_clone().ok_or(ProjError::RequestCloneError)?;
+    let with_headers = initial.headers(range_header(&range)?);
+    let _permit = config.limit();
+    let mut res = with_headers.send()?;
+    let eh_rb = req
+        .try_clone()
+        .ok_or(ProjError::RequestCloneError)?
+        .headers(range_header(&range)?);
+    // hand the response off to the error-handler, continue on success
+    error_handler(&mut res, eh_rb, config)?;
+    check_content_range(url, res.status(), res.headers(), offset)?;
+    let headers = res.headers().clone();
+    Ok(Chunk {
+        range,
+        headers,
+        bytes: read_body(config, res, &req, offset)?,
+    })
+}
+
+/// Check that a successful response to a range request holds the range starting at `offset`
+///
+/// A server which ignores the `Range` header responds with the whole file, which would otherwise
+/// be read as if it started at `offset`.
+pub(crate) fn check_content_range(
+    url: &str,
+    status: StatusCode,
+    headers: &HeaderMap,
+    offset: usize,
+) -> Result<(), ProjError> {
+    // e.g. "bytes 0-16383/1048576"
+    let start = headers
+        .get(CONTENT_RANGE)
+        .and_then(|range| range.to_str().ok())
+        .and_then(|range| range.strip_prefix("bytes "))
+        .and_then(|range| range.split('-').next())
+        .and_then(|start| start.trim().parse::<usize>().ok());
+    if status == StatusCode::PARTIAL_CONTENT && start == Some(offset) {
+        Ok(())
+    } else {
+        Err(ProjError::ContentRange(url.to_string(), offset))
+    }
+}
+
+/// Read the body of a successful range response which starts at `offset`
+///
+/// If the connection drops part-way through, the remainder is requested using a new range
+/// request, made conditional on the file not having changed in the meantime using `If-Range`.
+pub(crate) fn read_body(
+    config: &NetworkConfig,
+    mut res: Response,
+    request: &RequestBuilder,
+    offset: usize,
+) -> Result<Vec<u8>, ProjError> {
+    let url = res.url().to_string();
+    let expected = res.content_length().ok_or(ProjError::ContentLength)? as usize;
+    let validator = res
+        .headers()
+        .get(ETAG)
+        .or_else(|| res.headers().get(LAST_MODIFIED))
+        .cloned();
+    let mut body = Vec::with_capacity(expected);
+    let mut resumes = 0;
+    loop {
+        // Bytes read before an error are kept in `body`
+        let _ = res.read_to_end(&mut body);
+        if body.len() >= expected {
+            return Ok(body);
+        }
+        let validator = match &validator {
+            Some(validator) if resumes < MAX_RETRIES => validator,
+            _ => return Err(ProjError::IncompleteDownload(url, body.len(), expected)),
+        };
+        resumes += 1;
+        config.check_cancelled()?;
+        config.throttle();
+        res = request
+            .try_clone()
+            .ok_or(ProjError::RequestCloneError)?
+            .header(
+                RANGE,
+                format!("bytes={}-{}", offset + body.len(), offset + expected - 1),
+            )
+            .header(IF_RANGE, validator)
+            .send()?;
+        // Anything other than the requested range means the file has changed, or the server has
+        // stopped honouring range requests
+        if res.status() != StatusCode::PARTIAL_CONTENT {
+            return Err(ProjError::IncompleteDownload(url, body.len(), expected));
+        }
+        // The remainder is appended, so it must start where the body so far ends
+        check_content_range(&url, res.status(), res.headers(), offset + body.len())?;
+    }
+}
+
 /// Network callback: close connection and drop handle data (client and headers)
 pub(crate) unsafe extern "C" fn network_close(
     _: *mut PJ_CONTEXT,
     handle: *mut PROJ_NETWORK_HANDLE,
-    _: *mut c_void,
+    ud: *mut c_void,
 ) {
     // Reconstitute the Handle data so it can be dropped
     let hd = &*(handle as *const c_void as *mut HandleData);
+    let config = &*(ud as *const NetworkConfig);
+    config.observe(|o| o.download_finished(&hd.url));
     // Reconstitute and drop the header value returned by network_get_header_value,
     // since PROJ never explicitly returns it to us
     if let Some(header) = hd.hptr {
@@ -251,7 +1038,7 @@ fn _network_get_header_value(
         .get(&lookup)
         .ok_or_else(|| ProjError::HeaderError(lookup.to_string()))?
         .to_str()?;
-    let cstr = CString::new(hvalue).unwrap();
+    let cstr = CString::new(hvalue)?;
     let header = cstr.into_raw();
     // Raw pointers are Copy: the pointer returned by this function is never returned by libproj,so
     // in order to avoid a memory leak, the pointer is copied and stored in the HandleData struct,
@@ -292,11 +1079,12 @@ pub(crate) unsafe extern "C" fn network_read_range(
     ) {
         Ok(res) => res,
         Err(e) => {
+            let hd = &*(handle as *const c_void as *const HandleData);
+            let config = &*(ud as *const NetworkConfig);
+            let e = config.check_cancelled().err().unwrap_or(e);
+            config.observe(|o| o.download_failed(&hd.url, &e));
             // The assumption here is that if 0 is returned, whatever error is in out_error_string is displayed by libproj
-            // since this isn't a conversion using CString, nul chars must be manually stripped
-            let err_string = e.to_string().replace("0", "nought");
-            out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
-            out_error_string.add(err_string.len()).write(0);
+            write_error_string(&e.to_string(), error_string_max_size, out_error_string);
             0usize
         }
     }
@@ -309,44 +1097,126 @@ fn _network_read_range(
     offset: c_ulonglong,
     size_to_read: usize,
     buffer: *mut c_void,
-    _: usize,
+    error_string_max_size: usize,
     out_error_string: *mut c_char,
-    _: *mut c_void,
+    ud: *mut c_void,
 ) -> Result<usize, ProjError> {
+    let config = unsafe { &*(ud as *const NetworkConfig) };
+    let offset = offset as usize;
+    let hd = unsafe { &mut *(handle as *const c_void as *mut HandleData) };
+    // Serve the range from bytes read ahead by a previous request, if possible
+    if let Some(bytes) = hd.read_ahead(offset, size_to_read) {
+        unsafe {
+            bytes
+                .as_ptr()
+                .copy_to_nonoverlapping(buffer as *mut u8, size_to_read)
+        };
+        unsafe { write_error_string("", error_string_max_size, out_error_string) };
+        return Ok(size_to_read);
+    }
+    config.check_cancelled()?;
+    // Coalesce small reads into a single larger request
+    let fetch = size_to_read.max(config.readahead);
     // - 1 is used because the HTTP convention is to use inclusive start and end offsets
-    let end = offset as usize + size_to_read - 1;
+    let end = offset + fetch - 1;
     let hvalue = format!("bytes={}-{}", offset, end);
-    let mut hd = unsafe { &mut *(handle as *const c_void as *mut HandleData) };
-    let initial = hd.request.try_clone().ok_or(ProjError::RequestCloneError)?;
-    let with_headers = initial.header("Range", &hvalue).header("Client", CLIENT);
-    let mut res = with_headers.send()?;
-    let eh_rb = hd
-        .request
-        .try_clone()
-        .ok_or(ProjError::RequestCloneError)?
-        .header("Range", &hvalue);
-    // hand the response off to the error-handler, continue on success
-    error_handler(&mut res, eh_rb)?;
-    let headers = res.headers().clone();
-    let contentlength = res.content_length().ok_or(ProjError::ContentLength)? as usize;
+    let request = || match &hd.source {
+        Source::Http(request) => http_read_range(config, request, &hd.url, offset, hvalue),
+        #[cfg(feature = "network-object-store")]
+        Source::ObjectStore(store) => {
+            let _permit = config.limit();
+            store.read(&hd.url, offset, fetch)
+        }
+        #[cfg(feature = "test-util")]
+        Source::Replay => config
+            .recording
+            .as_ref()
+            .ok_or(ProjError::Network)?
+            .load(&hd.url, &hvalue),
+    };
+    let chunk = traced(&hd.url, offset, request, |chunk| chunk.bytes.len())?;
+    #[cfg(feature = "test-util")]
+    config.record(&hd.url, &chunk)?;
+    config.observe(|o| o.bytes_received(&hd.url, chunk.bytes.len()));
+    let contentlength = chunk.bytes.len().min(size_to_read);
     // Copy the downloaded bytes into the buffer so it can be passed around
     unsafe {
-        res.bytes()?
+        chunk
+            .bytes
             .as_ptr()
-            .copy_to_nonoverlapping(buffer as *mut u8, contentlength.min(size_to_read));
+            .copy_to_nonoverlapping(buffer as *mut u8, contentlength);
     }
-    let err_string = "";
-    unsafe {
-        out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
-        out_error_string.add(err_string.len()).write(0);
+    unsafe { write_error_string("", error_string_max_size, out_error_string) };
+    hd.headers = chunk.headers;
+    if config.readahead > 0 {
+        hd.readahead = Some((offset, chunk.bytes));
     }
-    hd.headers = headers;
     Ok(contentlength)
 }
 
-/// Set up and initialise the grid download callback functions for all subsequent PROJ contexts
-pub(crate) fn set_network_callbacks(ctx: *mut PJ_CONTEXT) -> i32 {
-    let ud: *mut c_void = ptr::null_mut();
+/// Make a grid request, within a `tracing` span recording its size and duration if the `tracing`
+/// feature is enabled
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn traced<T, R, B>(url: &str, offset: usize, request: R, bytes: B) -> Result<T, ProjError>
+where
+    R: FnOnce() -> Result<T, ProjError>,
+    B: Fn(&T) -> usize,
+{
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::field::Empty;
+        let span = tracing::debug_span!(
+            "grid_request",
+            url,
+            offset,
+            bytes = Empty,
+            duration_ms = Empty
+        )
+        .entered();
+        let started = Instant::now();
+        let response = request()?;
+        span.record("bytes", bytes(&response) as u64);
+        span.record("duration_ms", started.elapsed().as_millis() as u64);
+        Ok(response)
+    }
+    #[cfg(not(feature = "tracing"))]
+    request()
+}
+
+/// Write an error message into a buffer supplied by libproj
+///
+/// `max_size` is the size of the buffer, including the terminating nul. Longer messages are
+/// truncated to fit, and any nul characters in the message are removed so that libproj sees all of it.
+pub(crate) unsafe fn write_error_string(
+    message: &str,
+    max_size: usize,
+    out_error_string: *mut c_char,
+) {
+    if out_error_string.is_null() || max_size == 0 {
+        return;
+    }
+    let message = error_string(message, max_size - 1);
+    out_error_string.copy_from_nonoverlapping(message.as_ptr().cast(), message.len());
+    out_error_string.add(message.len()).write(0);
+}
+
+/// Remove nul characters from an error message, and truncate it to at most `max_len` bytes
+/// without splitting a character
+fn error_string(message: &str, max_len: usize) -> String {
+    let mut message: String = message.chars().filter(|&c| c != '\0').collect();
+    let mut len = message.len().min(max_len);
+    while !message.is_char_boundary(len) {
+        len -= 1;
+    }
+    message.truncate(len);
+    message
+}
+
+/// Set up and initialise the grid download callback functions for a PROJ context
+///
+/// `config` is handed to every callback, and must remain valid for the lifetime of `ctx`
+pub(crate) fn set_network_callbacks(ctx: *mut PJ_CONTEXT, config: *const NetworkConfig) -> i32 {
+    let ud = config as *mut c_void;
     unsafe {
         proj_context_set_network_callbacks(
             ctx,
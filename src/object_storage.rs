@@ -0,0 +1,85 @@
+//! Reading grids from an object store (S3, GCS, Azure Blob Storage) instead of over HTTP
+use crate::network::Chunk;
+use crate::proj::ProjError;
+use object_store::path::Path;
+use object_store::{GetOptions, ObjectStore};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_RANGE, ETAG, LAST_MODIFIED};
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+/// A bucket (or a prefix within one) holding a mirror of the PROJ CDN
+pub(crate) struct ObjectStoreSource {
+    store: Arc<dyn ObjectStore>,
+    prefix: Path,
+    /// `object_store` is async, but PROJ's network callbacks are not
+    runtime: Runtime,
+}
+
+impl ObjectStoreSource {
+    pub(crate) fn new(store: Arc<dyn ObjectStore>, prefix: &str) -> Result<Self, ProjError> {
+        Ok(Self {
+            store,
+            prefix: Path::parse(prefix).map_err(object_store::Error::from)?,
+            runtime: Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(ProjError::ObjectStoreRuntime)?,
+        })
+    }
+
+    /// Create a source from a URL such as `s3://bucket/prefix` or `gs://bucket/prefix`
+    ///
+    /// Credentials and other options are read from environment variables such as
+    /// `AWS_ACCESS_KEY_ID`, `GOOGLE_SERVICE_ACCOUNT`, or `AZURE_STORAGE_ACCOUNT_NAME`
+    pub(crate) fn from_url(url: &str) -> Result<Self, ProjError> {
+        let url = reqwest::Url::parse(url).map_err(|_| ProjError::ObjectStoreUrl(url.into()))?;
+        let options = std::env::vars().map(|(k, v)| (k.to_ascii_lowercase(), v));
+        let (store, prefix) = object_store::parse_url_opts(&url, options)?;
+        Self::new(Arc::from(store), prefix.as_ref())
+    }
+
+    /// Read `size` bytes starting at `offset` from the object corresponding to a grid URL
+    ///
+    /// Grids are looked up by file name under the prefix, so the bucket should have the same
+    /// layout as the CDN. The `Content-Range`, `Last-Modified`, and `ETag` headers PROJ uses
+    /// to check whether its cached copy is current are synthesized from the object's metadata.
+    pub(crate) fn read(&self, url: &str, offset: usize, size: usize) -> Result<Chunk, ProjError> {
+        let name = url.rsplit('/').next().unwrap_or(url);
+        let path = self.prefix.child(name);
+        let options = GetOptions {
+            range: Some((offset..offset + size).into()),
+            ..Default::default()
+        };
+        self.runtime.block_on(async {
+            let result = self.store.get_opts(&path, options).await?;
+            let meta = result.meta.clone();
+            let range = result.range.clone();
+            let bytes = result.bytes().await?.to_vec();
+            let mut headers = HeaderMap::new();
+            let content_range = format!(
+                "bytes {}-{}/{}",
+                range.start,
+                range.end.saturating_sub(1),
+                meta.size
+            );
+            let last_modified = meta
+                .last_modified
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string();
+            for (name, value) in [
+                (CONTENT_RANGE, Some(content_range)),
+                (LAST_MODIFIED, Some(last_modified)),
+                (ETAG, meta.e_tag),
+            ] {
+                if let Some(value) = value.and_then(|v| HeaderValue::from_str(&v).ok()) {
+                    headers.insert(name, value);
+                }
+            }
+            Ok(Chunk {
+                range: format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+                headers,
+                bytes,
+            })
+        })
+    }
+}
@@ -0,0 +1,54 @@
+//! Serializing and deserializing `Proj` instances as [PROJJSON](https://proj.org/specifications/projjson.html)
+//!
+//! With the `serde` feature enabled, `Proj` implements `Serialize` and `Deserialize`. A `Proj` is
+//! serialized as its PROJJSON object, and deserialized by creating a new instance from it, so it
+//! can be embedded in configuration files and other structs:
+//!
+//! ```rust
+//! use proj::Proj;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     crs: Proj,
+//! }
+//!
+//! let config = Config {
+//!     name: "Web Mercator".to_string(),
+//!     crs: Proj::new("EPSG:3857").unwrap(),
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! let config: Config = serde_json::from_str(&json).unwrap();
+//! ```
+//!
+//! The functions in this module can be used with `#[serde(with = "proj::projjson")]` for fields
+//! whose types wrap a `Proj`.
+use crate::proj::Proj;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{self, Serialize, Serializer};
+
+/// Serialize a `Proj` as its PROJJSON object
+pub fn serialize<S: Serializer>(proj: &Proj, serializer: S) -> Result<S::Ok, S::Error> {
+    let projjson = proj.to_projjson().map_err(ser::Error::custom)?;
+    let value: serde_json::Value = serde_json::from_str(&projjson).map_err(ser::Error::custom)?;
+    value.serialize(serializer)
+}
+
+/// Deserialize a `Proj` from a PROJJSON object
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Proj, D::Error> {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Proj::new(&value.to_string()).map_err(de::Error::custom)
+}
+
+impl Serialize for Proj {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Proj {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
@@ -0,0 +1,103 @@
+//! Recording grid responses to disk, and replaying them in place of network access
+use crate::network::Chunk;
+use crate::proj::ProjError;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Whether grid responses are recorded or replayed
+///
+/// See [`ProjBuilder::set_network_recording`](struct.ProjBuilder.html#method.set_network_recording).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RecordingMode {
+    /// Download grids as usual, saving each response
+    Record,
+    /// Answer requests using saved responses, without accessing the network
+    Replay,
+}
+
+/// A directory of recorded grid responses
+///
+/// Each response is stored as two files, named after the grid's host, file name, and the
+/// requested range: one containing the body, and one containing the headers.
+pub(crate) struct Recording {
+    dir: PathBuf,
+    mode: RecordingMode,
+}
+
+impl Recording {
+    pub(crate) fn new(dir: &Path, mode: RecordingMode) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            mode,
+        }
+    }
+
+    pub(crate) fn replaying(&self) -> bool {
+        self.mode == RecordingMode::Replay
+    }
+
+    /// The path of a recorded response's body or headers
+    fn path(&self, url: &str, range: &str, kind: &str) -> PathBuf {
+        let name: String = url
+            .splitn(2, "://")
+            .last()
+            .unwrap_or(url)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let range = range.trim_start_matches("bytes=");
+        self.dir.join(format!("{}.{}.{}", name, range, kind))
+    }
+
+    /// Load the recorded response to a range request
+    pub(crate) fn load(&self, url: &str, range: &str) -> Result<Chunk, ProjError> {
+        let bytes = fs::read(self.path(url, range, "body"))
+            .map_err(|_| ProjError::NotRecorded(url.to_string(), range.to_string()))?;
+        let raw_headers =
+            fs::read_to_string(self.path(url, range, "headers")).map_err(ProjError::Recording)?;
+        let mut headers = HeaderMap::new();
+        for line in raw_headers.lines() {
+            let mut parts = line.splitn(2, ": ");
+            let (name, value) = match (parts.next(), parts.next()) {
+                (Some(name), Some(value)) => (name, value),
+                _ => continue,
+            };
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+        Ok(Chunk {
+            range: range.to_string(),
+            headers,
+            bytes,
+        })
+    }
+
+    /// Save the response to a range request, if recording
+    pub(crate) fn save(&self, url: &str, chunk: &Chunk) -> Result<(), ProjError> {
+        if self.mode != RecordingMode::Record {
+            return Ok(());
+        }
+        let headers: String = chunk
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some(format!("{}: {}\n", name, value.to_str().ok()?)))
+            .collect();
+        fs::create_dir_all(&self.dir).map_err(ProjError::Recording)?;
+        fs::write(self.path(url, &chunk.range, "body"), &chunk.bytes)
+            .map_err(ProjError::Recording)?;
+        fs::write(self.path(url, &chunk.range, "headers"), headers)
+            .map_err(ProjError::Recording)?;
+        Ok(())
+    }
+}
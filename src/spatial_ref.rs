@@ -0,0 +1,48 @@
+//! Exchanging CRSs with the [`gdal`](https://docs.rs/gdal) crate
+use crate::proj::{_string, Proj, ProjError};
+use gdal::errors::GdalError;
+use gdal::spatial_ref::SpatialRef;
+use gdal_sys::OGRErr;
+use std::ffi::CString;
+use std::ptr;
+
+impl Proj {
+    /// Create a CRS from a GDAL [`SpatialRef`](https://docs.rs/gdal/latest/gdal/spatial_ref/struct.SpatialRef.html)
+    ///
+    /// The CRS is exchanged as WKT2, so no information is lost. As with CRS definitions passed to
+    /// [`new`](#method.new), the result can't transform coordinates: pass it to
+    /// [`new_known_crs`](#method.new_known_crs) using [`to_wkt`](#method.to_wkt) to do so.
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn from_gdal_spatial_ref(spatial_ref: &SpatialRef) -> Result<Proj, ProjError> {
+        let format = CString::new("FORMAT=WKT2_2018")?;
+        let options = [format.as_ptr(), ptr::null()];
+        let mut c_wkt = ptr::null_mut();
+        let rv = unsafe {
+            gdal_sys::OSRExportToWktEx(spatial_ref.to_c_hsrs(), &mut c_wkt, options.as_ptr())
+        };
+        let wkt = if rv == OGRErr::OGRERR_NONE && !c_wkt.is_null() {
+            _string(c_wkt)
+        } else {
+            Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRExportToWktEx",
+            }
+            .into())
+        };
+        unsafe { gdal_sys::VSIFree(c_wkt.cast()) };
+        Proj::new(&wkt?)
+    }
+
+    /// Convert a CRS into a GDAL [`SpatialRef`](https://docs.rs/gdal/latest/gdal/spatial_ref/struct.SpatialRef.html)
+    ///
+    /// The CRS is exchanged as WKT2, so no information is lost. Only instances created from a CRS
+    /// definition (rather than a transformation between CRSs) can be converted.
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn to_gdal_spatial_ref(&self) -> Result<SpatialRef, ProjError> {
+        Ok(SpatialRef::from_wkt(&self.to_wkt()?)?)
+    }
+}
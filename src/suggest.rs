@@ -0,0 +1,137 @@
+//! Custom projected CRS centred on a geometry, for measuring and displaying it with little
+//! distortion
+use crate::proj::{Proj, ProjError};
+use geo_types::{Coordinate, CoordinateType, Geometry};
+use num_traits::Float;
+
+/// Every coordinate of a geometry
+fn coords<T: CoordinateType>(geometry: &Geometry<T>) -> Vec<Coordinate<T>> {
+    match geometry {
+        Geometry::Point(point) => vec![point.0],
+        Geometry::Line(line) => vec![line.start, line.end],
+        Geometry::LineString(line) => line.0.clone(),
+        Geometry::Polygon(polygon) => std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| ring.0.iter().copied())
+            .collect(),
+        Geometry::MultiPoint(points) => points.0.iter().map(|point| point.0).collect(),
+        Geometry::MultiLineString(lines) => lines
+            .0
+            .iter()
+            .flat_map(|line| line.0.iter().copied())
+            .collect(),
+        Geometry::MultiPolygon(polygons) => polygons
+            .0
+            .iter()
+            .flat_map(|polygon| coords(&Geometry::Polygon(polygon.clone())))
+            .collect(),
+        Geometry::GeometryCollection(geometries) => geometries.0.iter().flat_map(coords).collect(),
+        Geometry::Rect(rect) => vec![rect.min(), rect.max()],
+        Geometry::Triangle(triangle) => vec![triangle.0, triangle.1, triangle.2],
+    }
+}
+
+/// The centre of the bounding box of a geometry's longitude, latitude coordinates, taking a
+/// geometry crossing the antimeridian into account
+fn centre<T: CoordinateType + Float>(geometry: &Geometry<T>) -> Result<(f64, f64), ProjError> {
+    let mut lons = vec![];
+    let mut lats = vec![];
+    for coord in coords(geometry) {
+        let lon = coord.x.to_f64().ok_or(ProjError::FloatConversion)?;
+        let lat = coord.y.to_f64().ok_or(ProjError::FloatConversion)?;
+        if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+            return Err(ProjError::InvalidArea(format!(
+                "({}, {}) isn't a longitude, latitude in degrees",
+                lon, lat
+            )));
+        }
+        lons.push(lon);
+        lats.push(lat);
+    }
+    let range = |values: &[f64]| {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (min, max)
+    };
+    if lons.is_empty() {
+        return Err(ProjError::EmptyGeometry);
+    }
+    let (mut west, mut east) = range(&lons);
+    // A geometry spanning more than half the globe may instead be a narrower one crossing the
+    // antimeridian
+    if east - west > 180.0 {
+        let shifted: Vec<f64> = lons
+            .iter()
+            .map(|&lon| if lon < 0.0 { lon + 360.0 } else { lon })
+            .collect();
+        let (shifted_west, shifted_east) = range(&shifted);
+        if shifted_east - shifted_west < east - west {
+            west = shifted_west;
+            east = shifted_east;
+        }
+    }
+    let (south, north) = range(&lats);
+    let lon = (west + east) / 2.0;
+    let lon = if lon > 180.0 { lon - 360.0 } else { lon };
+    Ok((lon, (south + north) / 2.0))
+}
+
+/// The WKT of the CRS defined by a PROJ string
+fn crs_wkt(definition: &str) -> Result<String, ProjError> {
+    Proj::new(definition)?.to_wkt()
+}
+
+/// Suggest an equal area CRS for a geometry: a Lambert Azimuthal Equal Area projection centred
+/// on it, on the WGS 84 datum
+///
+/// Areas measured in this CRS are correct, and shapes are distorted less the closer they are to
+/// the centre of the geometry, as in the EU's `ETRS89-extended / LAEA Europe`. This suits
+/// geometries of any shape up to the size of a continent. The geometry's coordinates must be
+/// longitudes and latitudes in degrees.
+///
+/// The CRS is returned as WKT, which can be passed to e.g.
+/// [`Proj::new_known_crs`](struct.Proj.html#method.new_known_crs).
+///
+/// ```rust
+/// use proj::{suggest_equal_area_crs, Proj};
+/// extern crate geo_types;
+/// use geo_types::{Geometry, LineString, Point, Polygon};
+/// # use assert_approx_eq::assert_approx_eq;
+///
+/// let exterior = LineString::from(vec![(5.0, 45.0), (15.0, 45.0), (15.0, 55.0), (5.0, 45.0)]);
+/// let triangle = Geometry::Polygon(Polygon::new(exterior, vec![]));
+/// let crs = suggest_equal_area_crs(&triangle).unwrap();
+/// let to_laea = Proj::new_known_crs("EPSG:4326", &crs, None).unwrap();
+/// // The centre of the geometry's bounding box is the origin
+/// let origin: Point<f64> = to_laea.convert((10.0, 50.0)).unwrap();
+/// assert_approx_eq!(origin.x(), 0.0, 1e-6);
+/// assert_approx_eq!(origin.y(), 0.0, 1e-6);
+/// ```
+pub fn suggest_equal_area_crs<T: CoordinateType + Float>(
+    geometry: &Geometry<T>,
+) -> Result<String, ProjError> {
+    let (lon, lat) = centre(geometry)?;
+    crs_wkt(&format!(
+        "+proj=laea +lat_0={} +lon_0={} +x_0=0 +y_0=0 +datum=WGS84 +units=m +no_defs +type=crs",
+        lat, lon
+    ))
+}
+
+/// Suggest a conformal CRS for a geometry: a Transverse Mercator projection centred on it, on
+/// the WGS 84 datum
+///
+/// Angles and local shapes are correct in this CRS, and scale is distorted less the closer
+/// points are to the geometry's central meridian, as in a UTM zone fitted to the geometry. This
+/// suits geometries which are narrow from east to west. The geometry's coordinates must be
+/// longitudes and latitudes in degrees.
+///
+/// The CRS is returned as WKT, as by [`suggest_equal_area_crs`](fn.suggest_equal_area_crs.html).
+pub fn suggest_conformal_crs<T: CoordinateType + Float>(
+    geometry: &Geometry<T>,
+) -> Result<String, ProjError> {
+    let (lon, lat) = centre(geometry)?;
+    crs_wkt(&format!(
+        "+proj=tmerc +lat_0={} +lon_0={} +k=1 +x_0=0 +y_0=0 +datum=WGS84 +units=m +no_defs +type=crs",
+        lat, lon
+    ))
+}
@@ -0,0 +1,119 @@
+//! Helpers for [slippy map](https://wiki.openstreetmap.org/wiki/Slippy_map_tilenames) tiles,
+//! which divide the Web Mercator (EPSG:3857) plane into `2^z` by `2^z` tiles at zoom level `z`
+//!
+//! ```rust
+//! use proj::tiles::Tile;
+//! use geo_types::Point;
+//!
+//! let tile = Tile::containing(Point::new(-0.1275, 51.5072), 10).unwrap();
+//! assert_eq!(tile.to_string(), "10/511/340");
+//! let bounds = tile.bounds();
+//! assert!(bounds.west() <= -0.1275 && -0.1275 <= bounds.east());
+//! ```
+use crate::proj::{Area, ProjError};
+use geo_types::{Coordinate, Point, Rect};
+use std::f64::consts::PI;
+use std::fmt;
+
+/// The semi-major axis of the WGS 84 ellipsoid, used by Web Mercator as the radius of a sphere
+const RADIUS: f64 = 6_378_137.0;
+
+/// Half the width of the Web Mercator plane, in metres
+pub const WEB_MERCATOR_EXTENT: f64 = PI * RADIUS;
+
+/// The latitude of the northern edge of the Web Mercator plane, in degrees
+const MAX_LATITUDE: f64 = 85.0511287798066;
+
+/// The highest zoom level whose tile indices fit in a `u32`
+pub const MAX_ZOOM: u8 = 31;
+
+/// A map tile, identified by its zoom level and its column (`x`) and row (`y`), counted from the
+/// north-west corner
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    /// Create a tile, checking that it exists at its zoom level
+    pub fn new(z: u8, x: u32, y: u32) -> Result<Tile, ProjError> {
+        if z > MAX_ZOOM || u64::from(x) >= 1 << z || u64::from(y) >= 1 << z {
+            return Err(ProjError::InvalidTile(z, x, y));
+        }
+        Ok(Tile { z, x, y })
+    }
+
+    /// The tile containing a point, given in degrees of longitude and latitude
+    ///
+    /// Latitudes beyond the limits of Web Mercator (about 85.05 degrees north and south) are
+    /// placed in the first or last row of tiles.
+    pub fn containing(point: Point<f64>, z: u8) -> Result<Tile, ProjError> {
+        if z > MAX_ZOOM {
+            return Err(ProjError::InvalidTile(z, 0, 0));
+        }
+        let (x, y) = to_web_mercator(point.x(), point.y());
+        let tiles = (1u64 << z) as f64;
+        let index = |metres: f64| {
+            let index =
+                ((metres + WEB_MERCATOR_EXTENT) / (2.0 * WEB_MERCATOR_EXTENT) * tiles).floor();
+            index.max(0.0).min(tiles - 1.0) as u32
+        };
+        Ok(Tile {
+            z,
+            x: index(x),
+            y: index(-y),
+        })
+    }
+
+    /// The tile's bounds, in Web Mercator metres
+    pub fn web_mercator_bounds(&self) -> Rect<f64> {
+        let size = 2.0 * WEB_MERCATOR_EXTENT / (1u64 << self.z) as f64;
+        let west = -WEB_MERCATOR_EXTENT + f64::from(self.x) * size;
+        let north = WEB_MERCATOR_EXTENT - f64::from(self.y) * size;
+        Rect::new(
+            Coordinate {
+                x: west,
+                y: north - size,
+            },
+            Coordinate {
+                x: west + size,
+                y: north,
+            },
+        )
+    }
+
+    /// The tile's bounds, in degrees of longitude and latitude
+    pub fn bounds(&self) -> Area {
+        let bounds = self.web_mercator_bounds();
+        let (west, south) = from_web_mercator(bounds.min().x, bounds.min().y);
+        let (east, north) = from_web_mercator(bounds.max().x, bounds.max().y);
+        Area::new(west, south, east, north).expect("tile bounds are within ±180° and ±85.06°")
+    }
+}
+
+impl fmt::Display for Tile {
+    /// Format the tile as `z/x/y`, as used in tile URLs
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.z, self.x, self.y)
+    }
+}
+
+/// Project degrees of longitude and latitude to Web Mercator metres
+fn to_web_mercator(lon: f64, lat: f64) -> (f64, f64) {
+    let y = (PI / 4.0 + lat.to_radians() / 2.0).tan().ln() * RADIUS;
+    (lon.to_radians() * RADIUS, y)
+}
+
+/// Unproject Web Mercator metres to degrees of longitude and latitude
+///
+/// The results are clamped to the plane's extent, as rounding puts its edges slightly beyond
+/// ±180 degrees.
+fn from_web_mercator(x: f64, y: f64) -> (f64, f64) {
+    let lat = 2.0 * (y / RADIUS).exp().atan() - PI / 2.0;
+    (
+        (x / RADIUS).to_degrees().clamp(-180.0, 180.0),
+        lat.to_degrees().clamp(-MAX_LATITUDE, MAX_LATITUDE),
+    )
+}
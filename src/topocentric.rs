@@ -0,0 +1,81 @@
+//! Transformations between global coordinates and a local East-North-Up frame
+use crate::proj::{Proj, ProjCoord, ProjError};
+
+/// The global coordinates which a local East-North-Up frame is transformed from or to, by
+/// [`Proj::to_local_enu`](struct.Proj.html#method.to_local_enu) and
+/// [`Proj::from_local_enu`](struct.Proj.html#method.from_local_enu)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GlobalCoordinates {
+    /// Longitude and latitude in degrees and ellipsoidal height in metres on WGS 84, as in
+    /// `EPSG:4979`
+    Geographic,
+    /// Geocentric `X, Y, Z` coordinates in metres on WGS 84, as in `EPSG:4978`
+    Geocentric,
+}
+
+/// The PROJ pipeline from `global` coordinates to the local frame at `origin`, or back if
+/// `inverse` is set
+fn pipeline(
+    origin: ProjCoord,
+    global: GlobalCoordinates,
+    inverse: bool,
+) -> Result<String, ProjError> {
+    let (lon, lat, height) = (origin.x, origin.y, origin.z.unwrap_or(0.0));
+    if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) || !height.is_finite() {
+        return Err(ProjError::InvalidArea(format!(
+            "({}, {}, {}) isn't a longitude, latitude in degrees and a height",
+            lon, lat, height
+        )));
+    }
+    let mut steps = vec![format!(
+        "+proj=topocentric +ellps=WGS84 +lon_0={} +lat_0={} +h_0={}",
+        lon, lat, height
+    )];
+    if global == GlobalCoordinates::Geographic {
+        steps.insert(0, "+proj=cart +ellps=WGS84".to_string());
+        steps.insert(0, "+proj=unitconvert +xy_in=deg +xy_out=rad".to_string());
+    }
+    if inverse {
+        steps.reverse();
+    }
+    let step = if inverse { " +step +inv " } else { " +step " };
+    Ok(steps
+        .iter()
+        .fold("+proj=pipeline".to_string(), |pipeline, definition| {
+            pipeline + step + definition
+        }))
+}
+
+impl Proj {
+    /// Create a transformation from global coordinates to a local East-North-Up frame, whose
+    /// origin is at `origin`'s longitude, latitude and ellipsoidal height on WGS 84
+    ///
+    /// The local coordinates are in metres: `x` points east, `y` north and `z` up, along the
+    /// ellipsoid's normal at the origin, as used by e.g. drones and robots navigating relative to
+    /// a base station. The transformation is a `+proj=topocentric` pipeline, which requires PROJ
+    /// 8.0 or later. An origin without a height is taken to be on the ellipsoid, and an invalid
+    /// origin returns an [`InvalidArea`](enum.ProjError.html#variant.InvalidArea) error.
+    ///
+    /// Use [`convert_coord`](#method.convert_coord) or [`convert_coords`](#method.convert_coords)
+    /// to convert coordinates with their heights.
+    ///
+    /// ```rust
+    /// use proj::{GlobalCoordinates, Proj, ProjCoord};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// let origin = ProjCoord::new(5.0, 55.0, 200.0);
+    /// let to_enu = Proj::to_local_enu(origin, GlobalCoordinates::Geographic).unwrap();
+    /// let enu = to_enu.convert_coord(ProjCoord::new(5.0, 55.0, 250.0)).unwrap();
+    /// assert_approx_eq!(enu.x, 0.0, 1e-6);
+    /// assert_approx_eq!(enu.y, 0.0, 1e-6);
+    /// assert_approx_eq!(enu.z.unwrap(), 50.0, 1e-6);
+    /// ```
+    pub fn to_local_enu(origin: ProjCoord, from: GlobalCoordinates) -> Result<Proj, ProjError> {
+        Proj::new(&pipeline(origin, from, false)?)
+    }
+
+    /// Create a transformation from a local East-North-Up frame to global coordinates: the
+    /// inverse of [`to_local_enu`](#method.to_local_enu)
+    pub fn from_local_enu(origin: ProjCoord, to: GlobalCoordinates) -> Result<Proj, ProjError> {
+        Proj::new(&pipeline(origin, to, true)?)
+    }
+}
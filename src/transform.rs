@@ -0,0 +1,54 @@
+use crate::proj::{Proj, ProjError};
+use geo_types::Point;
+
+/// A coordinate transformation, independent of the library carrying it out
+///
+/// Downstream crates can depend on this trait rather than on [`Proj`](struct.Proj.html), so that
+/// another backend (e.g. a pure-Rust implementation such as
+/// [`proj4rs`](https://docs.rs/proj4rs)) can be swapped in by implementing it.
+///
+/// ```rust
+/// use proj::{CoordTransform, Proj};
+/// use geo_types::Point;
+///
+/// fn to_web_mercator<T: CoordTransform>(transform: &T, lon: f64, lat: f64) -> Option<(f64, f64)> {
+///     let point = transform.transform_point(Point::new(lon, lat)).ok()?;
+///     Some((point.x(), point.y()))
+/// }
+///
+/// let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// let (x, _) = to_web_mercator(&proj, 10.0, 50.0).unwrap();
+/// assert!((x - 1113194.9).abs() < 0.1);
+/// ```
+pub trait CoordTransform {
+    type Error: std::error::Error;
+
+    /// Transform a single point
+    fn transform_point(&self, point: Point<f64>) -> Result<Point<f64>, Self::Error>;
+
+    /// Transform a slice of points in place
+    ///
+    /// The default implementation transforms the points one at a time, stopping at the first
+    /// error, so the points before it will already have been transformed. Implementations that can
+    /// transform points in a batch should override it.
+    fn transform_points(&self, points: &mut [Point<f64>]) -> Result<(), Self::Error> {
+        for point in points.iter_mut() {
+            *point = self.transform_point(*point)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transforms points with [`convert`](struct.Proj.html#method.convert) and
+/// [`convert_array`](struct.Proj.html#method.convert_array), leaving the slice unchanged on error
+impl CoordTransform for Proj {
+    type Error = ProjError;
+
+    fn transform_point(&self, point: Point<f64>) -> Result<Point<f64>, ProjError> {
+        self.convert(point)
+    }
+
+    fn transform_points(&self, points: &mut [Point<f64>]) -> Result<(), ProjError> {
+        self.convert_array(points).map(|_| ())
+    }
+}
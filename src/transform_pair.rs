@@ -0,0 +1,114 @@
+use crate::proj::{Area, Proj, ProjBuilder, ProjError};
+use geo_types::Point;
+use num_traits::Float;
+
+/// A pair of transformations between two CRS, one in each direction
+///
+/// A `Proj` created by [`new_known_crs`](struct.Proj.html#method.new_known_crs) only transforms
+/// from its source CRS to its target CRS, and passing it coordinates in the target CRS silently
+/// produces nonsense. `TransformPair` names the direction at each call instead, and creates both
+/// transformations with the same area of interest, so that comparable operations are chosen.
+///
+/// ```rust
+/// use proj::TransformPair;
+/// extern crate geo_types;
+/// use geo_types::Point;
+/// # use assert_approx_eq::assert_approx_eq;
+///
+/// let pair = TransformPair::new("EPSG:2230", "EPSG:26946", None).unwrap();
+/// let metres = pair.forward(Point::new(4760096.421921, 3744293.729449)).unwrap();
+/// assert_approx_eq!(metres.x(), 1450880.2910605003f64);
+/// let feet = pair.inverse(metres).unwrap();
+/// assert_approx_eq!(feet.x(), 4760096.421921f64, 1e-4);
+/// ```
+pub struct TransformPair {
+    forward: Proj,
+    inverse: Proj,
+}
+
+impl TransformPair {
+    /// Create transformations from `from` to `to` and back, as
+    /// [`new_known_crs`](struct.Proj.html#method.new_known_crs) does
+    ///
+    /// `area`, if given, is the area of interest for both directions.
+    pub fn new(from: &str, to: &str, area: Option<Area>) -> Result<TransformPair, ProjError> {
+        TransformPair::with_builders(ProjBuilder::new(), ProjBuilder::new(), from, to, area)
+    }
+
+    /// Create transformations from `from` to `to` and back, using customised builders, e.g. with
+    /// network access enabled
+    ///
+    /// Each transformation owns its context, so a builder is needed for each direction:
+    /// `forward` creates the transformation from `from` to `to`, and `inverse` the one back.
+    pub fn with_builders(
+        forward: ProjBuilder,
+        inverse: ProjBuilder,
+        from: &str,
+        to: &str,
+        area: Option<Area>,
+    ) -> Result<TransformPair, ProjError> {
+        Ok(TransformPair {
+            forward: forward.proj_known_crs(from, to, area)?,
+            inverse: inverse.proj_known_crs(to, from, area)?,
+        })
+    }
+
+    /// Transform a point from the source CRS to the target CRS
+    pub fn forward<T, U>(&self, point: T) -> Result<Point<U>, ProjError>
+    where
+        T: Into<Point<U>>,
+        U: Float,
+    {
+        self.forward.convert(point)
+    }
+
+    /// Transform a point from the target CRS back to the source CRS
+    pub fn inverse<T, U>(&self, point: T) -> Result<Point<U>, ProjError>
+    where
+        T: Into<Point<U>>,
+        U: Float,
+    {
+        self.inverse.convert(point)
+    }
+
+    /// Transform points from the source CRS to the target CRS in place, as
+    /// [`convert_array`](struct.Proj.html#method.convert_array) does
+    pub fn forward_array<'a, T: Float>(
+        &self,
+        points: &'a mut [Point<T>],
+    ) -> Result<&'a mut [Point<T>], ProjError> {
+        self.forward.convert_array(points)
+    }
+
+    /// Transform points from the target CRS back to the source CRS in place, as
+    /// [`convert_array`](struct.Proj.html#method.convert_array) does
+    pub fn inverse_array<'a, T: Float>(
+        &self,
+        points: &'a mut [Point<T>],
+    ) -> Result<&'a mut [Point<T>], ProjError> {
+        self.inverse.convert_array(points)
+    }
+
+    /// Set the bounding box of the area of interest of both directions
+    ///
+    /// See [`Proj::area_set_bbox`](struct.Proj.html#method.area_set_bbox).
+    pub fn area_set_bbox(&mut self, new_bbox: Area) {
+        self.forward.area_set_bbox(new_bbox);
+        self.inverse.area_set_bbox(new_bbox);
+    }
+
+    /// The transformation from the source CRS to the target CRS
+    pub fn forward_proj(&self) -> &Proj {
+        &self.forward
+    }
+
+    /// The transformation from the target CRS back to the source CRS
+    pub fn inverse_proj(&self) -> &Proj {
+        &self.inverse
+    }
+
+    /// The forward and inverse transformations
+    pub fn into_projs(self) -> (Proj, Proj) {
+        (self.forward, self.inverse)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::proj::{Proj, ProjBuilder, ProjCoord, ProjError};
+use geo_types::Point;
+use std::ops::Deref;
+
+/// A transformation object named and behaving like [pyproj](https://pyproj4.github.io/pyproj/)'s
+/// `Transformer`, to ease porting Python code
+///
+/// `Transformer` dereferences to [`Proj`](struct.Proj.html), so all of its methods are available
+/// too.
+///
+/// ```rust
+/// use proj::Transformer;
+/// # use assert_approx_eq::assert_approx_eq;
+///
+/// // Python: Transformer.from_crs("EPSG:4326", "EPSG:3857", always_xy=True)
+/// let transformer = Transformer::from_crs("EPSG:4326", "EPSG:3857", true).unwrap();
+/// let (x, y) = transformer.transform(10.0, 50.0).unwrap();
+/// assert_approx_eq!(x, 1113194.9079327357, 1e-6);
+/// ```
+pub struct Transformer {
+    proj: Proj,
+}
+
+impl Transformer {
+    /// Create a transformation between two CRS, like pyproj's `Transformer.from_crs`
+    ///
+    /// If `always_xy` is `true`, coordinates are in Longitude, Latitude / Easting, Northing order,
+    /// as with [`Proj::new_known_crs`](struct.Proj.html#method.new_known_crs). Otherwise they are in
+    /// the order specified by the CRS definitions, e.g. Latitude, Longitude for EPSG:4326, which is
+    /// pyproj's default.
+    pub fn from_crs(from: &str, to: &str, always_xy: bool) -> Result<Transformer, ProjError> {
+        let mut builder = ProjBuilder::new();
+        builder.set_normalize_axis_order(always_xy);
+        Ok(Transformer {
+            proj: builder.proj_known_crs(from, to, None)?,
+        })
+    }
+
+    /// Create a transformation from a PROJ pipeline definition, like pyproj's
+    /// `Transformer.from_pipeline`
+    pub fn from_pipeline(pipeline: &str) -> Result<Transformer, ProjError> {
+        Ok(Transformer {
+            proj: Proj::new(pipeline)?,
+        })
+    }
+
+    /// Transform a single coordinate, like pyproj's `Transformer.transform`
+    pub fn transform(&self, xx: f64, yy: f64) -> Result<(f64, f64), ProjError> {
+        let point = self.proj.convert(Point::new(xx, yy))?;
+        Ok((point.x(), point.y()))
+    }
+
+    /// Transform a single coordinate with a height, like pyproj's `Transformer.transform` with
+    /// `zz`
+    pub fn transform_3d(&self, xx: f64, yy: f64, zz: f64) -> Result<(f64, f64, f64), ProjError> {
+        let coord = self.proj.convert_coord(ProjCoord::new(xx, yy, zz))?;
+        // a three-dimensional input gives a three-dimensional output
+        Ok((coord.x, coord.y, coord.z.unwrap_or_default()))
+    }
+
+    /// Transform arrays of coordinates in place, like pyproj's `Transformer.transform` with
+    /// `inplace=True`
+    ///
+    /// As in pyproj, coordinates which can't be transformed are set to infinity. See
+    /// [`Proj::convert_columns`](struct.Proj.html#method.convert_columns).
+    pub fn transform_arrays(&self, xx: &mut [f64], yy: &mut [f64]) -> Result<(), ProjError> {
+        self.proj.convert_columns(xx, yy, None)
+    }
+
+    /// The underlying `Proj`
+    pub fn into_proj(self) -> Proj {
+        self.proj
+    }
+}
+
+impl Deref for Transformer {
+    type Target = Proj;
+
+    fn deref(&self) -> &Proj {
+        &self.proj
+    }
+}
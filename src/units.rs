@@ -0,0 +1,33 @@
+//! Variants of the distance, area and accuracy getters returning [`uom`](https://docs.rs/uom)
+//! quantities
+use crate::proj::{Area, Explanation, Proj, ProjError};
+use geo_types::Point;
+use num_traits::Float;
+use uom::si::area::square_meter;
+use uom::si::f64;
+use uom::si::length::meter;
+
+impl Proj {
+    /// Like [`geodesic_distance`](#method.geodesic_distance), but returning a `Length`
+    pub fn geodesic_distance_quantity<T: Float>(
+        &self,
+        a: Point<T>,
+        b: Point<T>,
+    ) -> Result<f64::Length, ProjError> {
+        Ok(f64::Length::new::<meter>(self.geodesic_distance(a, b)?))
+    }
+}
+
+impl Area {
+    /// Like [`surface_area`](#method.surface_area), but returning a `uom` `Area`
+    pub fn surface_area_quantity(&self) -> f64::Area {
+        f64::Area::new::<square_meter>(self.surface_area())
+    }
+}
+
+impl Explanation {
+    /// The operation's [`accuracy`](#structfield.accuracy) as a `Length`, if known
+    pub fn accuracy_quantity(&self) -> Option<f64::Length> {
+        self.accuracy.map(f64::Length::new::<meter>)
+    }
+}